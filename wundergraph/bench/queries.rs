@@ -2,7 +2,7 @@ extern crate criterion;
 extern crate diesel;
 extern crate diesel_migrations;
 extern crate juniper;
-extern crate serde_json;
+extern crate serde_json_internal as serde_json;
 extern crate wundergraph;
 extern crate wundergraph_bench;
 extern crate wundergraph_example;
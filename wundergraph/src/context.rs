@@ -7,6 +7,23 @@ pub trait WundergraphContext {
 
     /// Get a connection from the context
     fn get_connection(&self) -> &Self::Connection;
+
+    /// Limit applied to top level queries without a `limit` argument
+    ///
+    /// Used for all entities not setting `LoadingHandler::DEFAULT_LIMIT`.
+    /// Defaults to no limit
+    fn default_limit(&self) -> Option<i64> {
+        None
+    }
+
+    /// Largest limit a top level query may request
+    ///
+    /// Used for all entities not setting `LoadingHandler::MAX_LIMIT`.
+    /// Queries requesting more entities are rejected with
+    /// `WundergraphError::LimitExceeded`. Defaults to no maximum
+    fn max_limit(&self) -> Option<i64> {
+        None
+    }
 }
 
 impl<Conn> WundergraphContext for Conn
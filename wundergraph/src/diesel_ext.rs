@@ -6,6 +6,10 @@ use diesel::query_builder::{AstPass, QueryFragment, QueryId};
 use diesel::result::QueryResult;
 use diesel::sql_types::IntoNullable;
 
+#[doc(inline)]
+#[cfg(all(feature = "regex", feature = "sqlite"))]
+pub use crate::third_party_integrations::regex::register_regexp_function;
+
 /// A helper trait used when boxing filters
 ///
 /// In Rust you cannot create a trait object with more than one trait.
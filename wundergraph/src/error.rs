@@ -10,6 +10,74 @@ pub enum WundergraphError {
     /// graphql arguments
     #[error("Could not build filter from arguments")]
     CouldNotBuildFilterArgument,
+    /// Indicates that the filter argument contains a value that
+    /// could not be interpreted by wundergraph
+    #[error("Invalid filter argument at `{path}`")]
+    InvalidFilterArgument {
+        /// The path of the invalid value, for example `filter.heros.some.heroName.eq`
+        path: String,
+    },
+    /// Indicates that the order argument contains a value that
+    /// could not be interpreted by wundergraph
+    #[error("Invalid order argument at `{path}`")]
+    InvalidOrderArgument {
+        /// The path of the invalid value, for example `order[0].column`
+        path: String,
+    },
+    /// Indicates that the limit argument is not a non negative integer
+    #[error("Invalid limit argument, expected a non negative integer")]
+    InvalidLimitArgument,
+    /// Indicates that the offset argument is not a non negative integer
+    #[error("Invalid offset argument, expected a non negative integer")]
+    InvalidOffsetArgument,
+    /// Indicates that a pagination argument like `first` or `last`
+    /// is not a non negative integer
+    #[error("Invalid `{name}` argument, expected a non negative integer")]
+    InvalidPaginationArgument {
+        /// The name of the invalid argument
+        name: String,
+    },
+    /// Indicates that a query requested more entities than allowed
+    #[error("Requested `{name}` of {limit} exceeds the maximum of {max}")]
+    LimitExceeded {
+        /// The name of the argument containing the limit
+        name: String,
+        /// The requested limit
+        limit: i64,
+        /// The maximal allowed limit
+        max: i64,
+    },
+    /// Indicates that a cursor passed to `after` or `before` could not
+    /// be decoded
+    #[error("Invalid cursor passed to `{name}`")]
+    InvalidCursor {
+        /// The name of the argument containing the invalid cursor
+        name: String,
+    },
+    /// Indicates that a field used to order a keyset paginated connection
+    /// could not be encoded into a cursor
+    #[error(
+        "Field `{name}` could not be used for keyset pagination, \
+         only non nullable scalar fields are supported"
+    )]
+    UnsupportedKeysetColumn {
+        /// The name of the unsupported field
+        name: String,
+    },
+    /// Indicates that the groupBy argument contains a value that
+    /// could not be interpreted by wundergraph
+    #[error("Invalid groupBy argument at `{path}`")]
+    InvalidGroupByArgument {
+        /// The path of the invalid value, for example `groupBy[0]`
+        path: String,
+    },
+    /// Indicates that the key of a group requested a field that is
+    /// not part of the groupBy argument
+    #[error("Field `{name}` is not part of the groupBy argument")]
+    UngroupedField {
+        /// The name of the requested field
+        name: String,
+    },
     /// Indicates that a unknown database field name was passed into
     /// wundergraph
     #[error("Requested unkown field {name}")]
@@ -21,6 +89,13 @@ pub enum WundergraphError {
     /// given arguments
     #[error("Could not build primary key filter from arguments")]
     NoPrimaryKeyArgumentFound,
+    /// Indicates that the argument of a lookup by a unique field
+    /// is missing
+    #[error("Could not find the argument of unique field `{name}`")]
+    NoUniqueArgumentFound {
+        /// The name of the unique field
+        name: String,
+    },
     /// Indicates that building a graphql return value failed
     #[error("Failed to build a return value")]
     JuniperError {
@@ -52,6 +52,15 @@ pub trait WundergraphGraphqlMapper<DB, Ctx> {
     ) -> meta::Field<'r, WundergraphScalarValue> {
         field
     }
+
+    /// Fields registered next to the field of this type, for example
+    /// the `{name}Count` field of a `HasMany` field
+    fn register_additional_fields<'r>(
+        _name: &str,
+        _registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<meta::Field<'r, WundergraphScalarValue>> {
+        Vec::new()
+    }
 }
 
 impl<T, DB, Ctx> WundergraphGraphqlMapper<DB, Ctx> for T
@@ -92,7 +101,7 @@ macro_rules! wundergraph_graphql_helper_impl {
                 ) -> meta::MetaType<'r, WundergraphScalarValue>
                     where Type: GraphQLType<WundergraphScalarValue, TypeInfo = ()>
                 {
-                    let fields  = [
+                    let mut fields  = vec![
                         $({
                             let mut field = registry.field::<<$T as WundergraphGraphqlMapper<Back, Ctx>>::GraphQLType>(names[$idx], &());
                             field = <$T as WundergraphGraphqlMapper<Back, Ctx>>::register_arguments(registry, field);
@@ -105,6 +114,10 @@ macro_rules! wundergraph_graphql_helper_impl {
                             field
                         },)*
                     ];
+                    $(
+                        let additional_fields = <$T as WundergraphGraphqlMapper<Back, Ctx>>::register_additional_fields(names[$idx], registry);
+                        fields.extend(additional_fields);
+                    )*
                     let mut ty = registry.build_object_type::<Type>(
                         &(),
                         &fields,
@@ -107,3 +107,21 @@ impl Nameable for () {
         String::new()
     }
 }
+
+impl<A, B> Nameable for (A, B)
+where
+    A: Nameable,
+    B: Nameable,
+{
+    fn name() -> String {
+        let a = A::name();
+        let b = B::name();
+        if a.is_empty() {
+            b
+        } else if b.is_empty() {
+            a
+        } else {
+            format!("{}_{}", a, b)
+        }
+    }
+}
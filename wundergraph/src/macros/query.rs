@@ -110,6 +110,10 @@ macro_rules! __expand_filter {
             $crate::query_builder::selection::filter::Filter<<$graphql_struct as $crate::query_builder::selection::LoadingHandler<<$conn as $crate::diesel::Connection>::Backend, Ctx>>::Filter, <$graphql_struct as $crate::diesel::associations::HasTable>::Table>
             >>("filter", &None, &Default::default());
         $entity = $entity.argument(arg);
+        if <$graphql_struct as $crate::query_builder::selection::LoadingHandler<<$conn as $crate::diesel::Connection>::Backend, Ctx>>::FULL_TEXT_SEARCH {
+            let arg = $registry.arg_with_default::<Option<String>>("search", &None, &());
+            $entity = $entity.argument(arg);
+        }
     };
     (
         $registry: ident, $entity: ident, $conn: ty, $graphql_struct: ident,
@@ -138,6 +142,263 @@ macro_rules! __expand_filter {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_connection {
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*], meta = []
+    ) => {};
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*],
+        meta = [#[wundergraph(connection = true $($stuff:tt)*)], $($rest:tt)*]
+    ) => {
+        $crate::__expand_connection!(
+            @register $registry, $fields, $conn, $graphql_struct, $name, all = [$($all)*]
+        )
+    };
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*],
+        meta = [#[wundergraph(connection = keyset $($stuff:tt)*)], $($rest:tt)*]
+    ) => {
+        $crate::__expand_connection!(
+            @register $registry, $fields, $conn, $graphql_struct, $name, all = [$($all)*]
+        )
+    };
+    (
+        @register $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*]
+    ) => {
+        let mut field = $registry.field::<$crate::query_builder::selection::connection::Connection<
+            $graphql_struct,
+            <$conn as $crate::diesel::Connection>::Backend,
+            Ctx,
+        >>(concat!($name, "Connection"), &Default::default());
+        $crate::__expand_meta!(field, $($all)*);
+        $crate::__expand_filter!($registry, field, $conn, $graphql_struct, meta = [$($all)*]);
+        $crate::__expand_order!($registry, field, $conn, $graphql_struct, meta = [$($all)*]);
+        field = $crate::query_builder::selection::connection::register_connection_arguments(
+            $registry,
+            field,
+        );
+        $fields.push(field);
+    };
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*],
+        meta = [#[wundergraph(connection = false $($stuff:tt)*)], $($rest:tt)*]
+    ) => {};
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*],
+        meta = [#[wundergraph($stuff:tt $($other_stuff:tt)*)], $($rest:tt)*]
+    ) => {
+        $crate::__expand_connection!(
+            $registry, $fields, $conn, $graphql_struct, $name,
+            all = [$($all)*],
+            meta = [#[wundergraph($($other_stuff)*)], $($rest)*]
+        )
+    };
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*],
+        meta = [#[$($stuff:tt)*], $($rest:tt)*]
+    ) => {
+        $crate::__expand_connection!(
+            $registry, $fields, $conn, $graphql_struct, $name,
+            all = [$($all)*],
+            meta = [$($rest)*]
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_count {
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*], meta = []
+    ) => {};
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*],
+        meta = [#[wundergraph(count = true $($stuff:tt)*)], $($rest:tt)*]
+    ) => {
+        let mut field = $registry.field::<i64>(concat!($name, "Count"), &());
+        $crate::__expand_meta!(field, $($all)*);
+        $crate::__expand_filter!($registry, field, $conn, $graphql_struct, meta = [$($all)*]);
+        $fields.push(field);
+    };
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*],
+        meta = [#[wundergraph(count = false $($stuff:tt)*)], $($rest:tt)*]
+    ) => {};
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*],
+        meta = [#[wundergraph($stuff:tt $($other_stuff:tt)*)], $($rest:tt)*]
+    ) => {
+        $crate::__expand_count!(
+            $registry, $fields, $conn, $graphql_struct, $name,
+            all = [$($all)*],
+            meta = [#[wundergraph($($other_stuff)*)], $($rest)*]
+        )
+    };
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*],
+        meta = [#[$($stuff:tt)*], $($rest:tt)*]
+    ) => {
+        $crate::__expand_count!(
+            $registry, $fields, $conn, $graphql_struct, $name,
+            all = [$($all)*],
+            meta = [$($rest)*]
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_aggregate {
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*], meta = []
+    ) => {};
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*],
+        meta = [#[wundergraph(aggregate = true $($stuff:tt)*)], $($rest:tt)*]
+    ) => {
+        let mut field = $registry.field::<$crate::query_builder::selection::aggregate::Aggregates<
+            $graphql_struct,
+            <$conn as $crate::diesel::Connection>::Backend,
+            Ctx,
+        >>(concat!($name, "Aggregate"), &Default::default());
+        $crate::__expand_meta!(field, $($all)*);
+        $crate::__expand_filter!($registry, field, $conn, $graphql_struct, meta = [$($all)*]);
+        $fields.push(field);
+    };
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*],
+        meta = [#[wundergraph(aggregate = false $($stuff:tt)*)], $($rest:tt)*]
+    ) => {};
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*],
+        meta = [#[wundergraph($stuff:tt $($other_stuff:tt)*)], $($rest:tt)*]
+    ) => {
+        $crate::__expand_aggregate!(
+            $registry, $fields, $conn, $graphql_struct, $name,
+            all = [$($all)*],
+            meta = [#[wundergraph($($other_stuff)*)], $($rest)*]
+        )
+    };
+    (
+        $registry: ident, $fields: ident, $conn: ty, $graphql_struct: ident, $name: expr,
+        all = [$($all:tt)*],
+        meta = [#[$($stuff:tt)*], $($rest:tt)*]
+    ) => {
+        $crate::__expand_aggregate!(
+            $registry, $fields, $conn, $graphql_struct, $name,
+            all = [$($all)*],
+            meta = [$($rest)*]
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_by_ids {
+    (
+        $registry: ident, $fields: ident, $info: ident, $graphql_struct: ident,
+        all = [$($all:tt)*], meta = []
+    ) => {};
+    (
+        $registry: ident, $fields: ident, $info: ident, $graphql_struct: ident,
+        all = [$($all:tt)*],
+        meta = [#[wundergraph(by_ids = true $($stuff:tt)*)], $($rest:tt)*]
+    ) => {
+        let key_info = $crate::helper::PrimaryKeyInfo::default();
+        let keys = $registry.arg::<
+            Vec<$crate::helper::PrimaryKeyArgument<
+                'static,
+                <$graphql_struct as $crate::diesel::associations::HasTable>::Table,
+                Ctx,
+                <&'static $graphql_struct as $crate::diesel::Identifiable>::Id
+            >>
+        >("primaryKeys", &key_info);
+        let field = $registry.field::<Vec<Option<$crate::graphql_type::GraphqlWrapper<
+            $graphql_struct,
+            <<Ctx as $crate::WundergraphContext>::Connection as $crate::diesel::Connection>::Backend,
+            Ctx,
+        >>>>(concat!(stringify!($graphql_struct), "ByIds"), $info).argument(keys);
+        $fields.push(field);
+    };
+    (
+        $registry: ident, $fields: ident, $info: ident, $graphql_struct: ident,
+        all = [$($all:tt)*],
+        meta = [#[wundergraph(by_ids = false $($stuff:tt)*)], $($rest:tt)*]
+    ) => {};
+    (
+        $registry: ident, $fields: ident, $info: ident, $graphql_struct: ident,
+        all = [$($all:tt)*],
+        meta = [#[wundergraph($stuff:tt $($other_stuff:tt)*)], $($rest:tt)*]
+    ) => {
+        $crate::__expand_by_ids!(
+            $registry, $fields, $info, $graphql_struct,
+            all = [$($all)*],
+            meta = [#[wundergraph($($other_stuff)*)], $($rest)*]
+        )
+    };
+    (
+        $registry: ident, $fields: ident, $info: ident, $graphql_struct: ident,
+        all = [$($all:tt)*],
+        meta = [#[$($stuff:tt)*], $($rest:tt)*]
+    ) => {
+        $crate::__expand_by_ids!(
+            $registry, $fields, $info, $graphql_struct,
+            all = [$($all)*],
+            meta = [$($rest)*]
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_load_connection {
+    ($graphql_struct: ident, $look_ahead: expr, $selection: expr, $executor: expr, meta = []) => {
+        $graphql_struct::load_connection($look_ahead, $selection, $executor)
+    };
+    (
+        $graphql_struct: ident, $look_ahead: expr, $selection: expr, $executor: expr,
+        meta = [#[wundergraph(connection = keyset $($stuff:tt)*)], $($rest:tt)*]
+    ) => {
+        $graphql_struct::load_keyset_connection($look_ahead, $selection, $executor)
+    };
+    (
+        $graphql_struct: ident, $look_ahead: expr, $selection: expr, $executor: expr,
+        meta = [#[wundergraph($stuff:tt $($other_stuff:tt)*)], $($rest:tt)*]
+    ) => {
+        $crate::__expand_load_connection!(
+            $graphql_struct, $look_ahead, $selection, $executor,
+            meta = [#[wundergraph($($other_stuff)*)], $($rest)*]
+        )
+    };
+    (
+        $graphql_struct: ident, $look_ahead: expr, $selection: expr, $executor: expr,
+        meta = [#[$($stuff:tt)*], $($rest:tt)*]
+    ) => {
+        $crate::__expand_load_connection!(
+            $graphql_struct, $look_ahead, $selection, $executor,
+            meta = [$($rest)*]
+        )
+    };
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __expand_meta {
@@ -215,6 +476,8 @@ macro_rules! __impl_graphql_obj_for_query {
             where Ctx: $crate::WundergraphContext,
                   DB: $crate::diesel::backend::Backend + $crate::query_builder::selection::offset::ApplyOffset + 'static,
                   DB::QueryBuilder: std::default::Default,
+                  DB: $crate::diesel::sql_types::HasSqlType<$crate::diesel::sql_types::BigInt>,
+                  i64: $crate::diesel::deserialize::FromSql<$crate::diesel::sql_types::BigInt, DB>,
                   Ctx::Connection: $crate::diesel::Connection<Backend = DB>,
             $([<$graphql_struct _table>]: $crate::diesel::Table + $crate::diesel::query_dsl::methods::BoxedDsl<
               'static,
@@ -265,6 +528,36 @@ macro_rules! __impl_graphql_obj_for_query {
                   <[<$graphql_struct _id>] as $crate::helper::UnRef<'static>>::UnRefed, ()
                   >,)*
                 $([<$graphql_struct _table>]::PrimaryKey: $crate::diesel::EqAll<<[<$graphql_struct _id>] as $crate::helper::UnRef<'static>>::UnRefed>,)*
+                $([<$graphql_struct _table>]::PrimaryKey: $crate::diesel::query_builder::QueryFragment<DB>,)*
+                $(<[<$graphql_struct _id>] as $crate::helper::UnRef<'static>>::UnRefed: std::hash::Hash + std::cmp::Eq + std::clone::Clone,)*
+                $(DB: $crate::diesel::sql_types::HasSqlType<(
+                  $crate::diesel::dsl::SqlTypeOf<[<$graphql_struct _table>]::PrimaryKey>,
+                  $crate::query_builder::selection::SqlTypeOfPlaceholder<
+                  <$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::FieldList,
+                  DB,
+                  <$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::PrimaryKeyIndex,
+                  [<$graphql_struct _table>],
+                  Ctx
+                  >
+                )>,)*
+                $((
+                  <[<$graphql_struct _id>] as $crate::helper::UnRef<'static>>::UnRefed,
+                  <<$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::FieldList as $crate::query_builder::selection::fields::WundergraphFieldList<
+                  DB,
+                  <$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::PrimaryKeyIndex,
+                  [<$graphql_struct _table>],
+                  Ctx,
+                  >>::PlaceHolder,
+                ): $crate::diesel::Queryable<(
+                  $crate::diesel::dsl::SqlTypeOf<[<$graphql_struct _table>]::PrimaryKey>,
+                  $crate::query_builder::selection::SqlTypeOfPlaceholder<
+                  <$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::FieldList,
+                  DB,
+                  <$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::PrimaryKeyIndex,
+                  [<$graphql_struct _table>],
+                  Ctx
+                  >
+                ), DB>,)*
                 $(<[<$graphql_struct _table>]::PrimaryKey as $crate::diesel::EqAll<<[<$graphql_struct _id>] as $crate::helper::UnRef<'static>>::UnRefed>>::Output: $crate::diesel::AppearsOnTable<[<$graphql_struct _table>]> + $crate::diesel::query_builder::QueryFragment<DB> + $crate::diesel::expression::NonAggregate,)*
                 $(<<$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::Filter as $crate::query_builder::selection::filter::BuildFilter<DB>>::Ret: $crate::diesel::AppearsOnTable<[<$graphql_struct _table>]>,)*
                 $(<<$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::FieldList as $crate::query_builder::selection::fields::FieldListExtractor>::Out:
@@ -358,7 +651,8 @@ macro_rules! __impl_graphql_obj_for_query {
 ///         // Additionally there are a few attributes to control the generated
 ///         // field:
 ///         // * `#[wundergraph(filter = true)]` Specifies if a filter
-///         //   argument is generated for the current entity.
+///         //   argument is generated for the current entity. Entities
+///         //   supporting full text search also get a `search` argument.
 ///         //   Possible Values: true, false
 ///         // * `#[wundergraph(limit = true)]` Specifies if a limit
 ///         //   argument is generated for the current entity.
@@ -369,12 +663,46 @@ macro_rules! __impl_graphql_obj_for_query {
 ///         // * `#[wundergraph(order = true)]` Specifies if an order clause
 ///         //   argument is generated for the current entity.
 ///         //   Possible Values: true, false
+///         // * `#[wundergraph(connection = true)]` Specifies if an additional
+///         //   `{name}Connection` field exposing the entity as relay
+///         //   connection with `first`, `after`, `last` and `before`
+///         //   arguments is generated. With `keyset` cursors encode the
+///         //   values of the order columns and the primary key instead of
+///         //   the position of an entity, which keeps pages stable and fast
+///         //   on large tables. Only fields backed by a column of the
+///         //   entity table that do not contain `NULL` values could be used
+///         //   to order a keyset paginated connection.
+///         //   Possible Values: true, keyset, false
+///         // * `#[wundergraph(count = true)]` Specifies if an additional
+///         //   `{name}Count` field returning the number of entities matching
+///         //   the `filter` argument is generated.
+///         //   Possible Values: true, false
+///         // * `#[wundergraph(aggregate = true)]` Specifies if an additional
+///         //   `{name}Aggregate` field returning the number of entities
+///         //   matching the `filter` argument together with `sum`, `avg`,
+///         //   `min` and `max` of their numeric and temporal fields is
+///         //   generated. Its `groups(groupBy: [...])` field returns those
+///         //   aggregates for each group of entities sharing the values of
+///         //   the given columns.
+///         //   Possible Values: true, false
+///         // * `#[wundergraph(by_ids = true)]` Specifies if an additional
+///         //   `{Entity}ByIds(primaryKeys: [...])` field is generated, loading
+///         //   the entities with the given primary keys by a single query. The
+///         //   result contains one entry for each key in the requested order,
+///         //   `null` for keys without entity.
+///         //   Possible Values: true, false
+///         //
+///         // Fields marked with `#[wundergraph(unique)]` in the entity
+///         // definition get an additional `{Entity}By{Field}(field: ...)`
+///         // lookup field, for example `HeroByName(name: ...)`.
 ///         //
-///         // Default values for all options are true. As shown below it is
-///         // possible to have multiple flags in one attribute.
+///         // Default values for all options except `connection`, `count`,
+///         // `aggregate` and `by_ids` are true. As shown below it is possible to have
+///         // multiple flags in one attribute.
 ///         //
 ///         #[wundergraph(filter = false)]
 ///         #[wundergraph(offset = true, order = false, limit = false,)]
+///         #[wundergraph(connection = true)]
 ///         Species,
 ///         // It is also possible to register additional arguments for entities.
 ///         // To use this arguments it is required to provide a manual
@@ -545,7 +873,7 @@ macro_rules! query_object {
                     ) -> $crate::juniper::meta::MetaType<'r, $crate::scalar::WundergraphScalarValue>
                     where $crate::scalar::WundergraphScalarValue: 'r
                     {
-                        let fields = &[
+                        let mut fields = vec![
                             $(
                                 {
                                     let mut field = registry.field::<Vec<$crate::graphql_type::GraphqlWrapper<
@@ -599,7 +927,49 @@ macro_rules! query_object {
 
                             )*
                         ];
-                        let mut obj = registry.build_object_type::<Self>(info, fields);
+                        $(
+                            $crate::__expand_connection!(
+                                registry,
+                                fields,
+                                <Ctx as $crate::WundergraphContext>::Connection,
+                                $graphql_struct,
+                                $crate::__expand_name!($graphql_struct, $(#[$($meta)*],)*),
+                                all = [$(#[$($meta)*],)*],
+                                meta = [$(#[$($meta)*],)*]
+                            );
+                            $crate::__expand_count!(
+                                registry,
+                                fields,
+                                <Ctx as $crate::WundergraphContext>::Connection,
+                                $graphql_struct,
+                                $crate::__expand_name!($graphql_struct, $(#[$($meta)*],)*),
+                                all = [$(#[$($meta)*],)*],
+                                meta = [$(#[$($meta)*],)*]
+                            );
+                            $crate::__expand_aggregate!(
+                                registry,
+                                fields,
+                                <Ctx as $crate::WundergraphContext>::Connection,
+                                $graphql_struct,
+                                $crate::__expand_name!($graphql_struct, $(#[$($meta)*],)*),
+                                all = [$(#[$($meta)*],)*],
+                                meta = [$(#[$($meta)*],)*]
+                            );
+                            $crate::__expand_by_ids!(
+                                registry,
+                                fields,
+                                info,
+                                $graphql_struct,
+                                all = [$(#[$($meta)*],)*],
+                                meta = [$(#[$($meta)*],)*]
+                            );
+                            fields.extend($crate::query_builder::selection::unique::register_unique_lookups::<
+                                $graphql_struct,
+                                <<Ctx as $crate::WundergraphContext>::Connection as $crate::diesel::Connection>::Backend,
+                                Ctx,
+                            >(stringify!($graphql_struct), registry));
+                        )*
+                        let mut obj = registry.build_object_type::<Self>(info, &fields);
                         obj = obj.description(concat!($($glob_doc, "\n", )* ""));
                         obj.into_meta()
                     }
@@ -619,6 +989,7 @@ macro_rules! query_object {
                                 $crate::__expand_name!($graphql_struct, $(#[$($meta)*],)*) => {
                                     let look_ahead = executor.look_ahead();
                                     let q = $graphql_struct::build_query(look_ahead.arguments(), &look_ahead)?;
+                                    let q = $graphql_struct::apply_limit_policy(q, &look_ahead, executor.context())?;
                                     let items = $graphql_struct::load(&look_ahead, self.1, executor, q)?;
                                     Ok($crate::juniper::Value::List(items))
                                 },
@@ -628,6 +999,44 @@ macro_rules! query_object {
                                     let item = $graphql_struct::load_by_primary_key(&look_ahead, self.1, executor, q)?;
                                     Ok(item.unwrap_or($crate::juniper::Value::Null))
                                 }
+                                concat!(stringify!($graphql_struct), "ByIds") => {
+                                    let look_ahead = executor.look_ahead();
+                                    let q = $graphql_struct::build_query(look_ahead.arguments(), &look_ahead)?;
+                                    let items = $graphql_struct::load_by_primary_keys(&look_ahead, self.1, executor, q)?;
+                                    Ok($crate::juniper::Value::List(items))
+                                }
+                                concat!($crate::__expand_name!($graphql_struct, $(#[$($meta)*],)*), "Connection") => {
+                                    let look_ahead = executor.look_ahead();
+                                    Ok($crate::__expand_load_connection!(
+                                        $graphql_struct,
+                                        &look_ahead,
+                                        self.1,
+                                        executor,
+                                        meta = [$(#[$($meta)*],)*]
+                                    )?)
+                                }
+                                concat!($crate::__expand_name!($graphql_struct, $(#[$($meta)*],)*), "Count") => {
+                                    let look_ahead = executor.look_ahead();
+                                    let count = $graphql_struct::load_count(&look_ahead, executor)?;
+                                    Ok($crate::juniper::Value::scalar(count))
+                                }
+                                concat!($crate::__expand_name!($graphql_struct, $(#[$($meta)*],)*), "Aggregate") => {
+                                    let look_ahead = executor.look_ahead();
+                                    Ok($graphql_struct::load_aggregates(&look_ahead, self.1, executor)?)
+                                }
+                                e if $crate::query_builder::selection::unique::unique_field::<$graphql_struct, DB, Ctx>(
+                                    stringify!($graphql_struct),
+                                    e,
+                                ).is_some() => {
+                                    let field = $crate::query_builder::selection::unique::unique_field::<$graphql_struct, DB, Ctx>(
+                                        stringify!($graphql_struct),
+                                        e,
+                                    ).expect("Checked above");
+                                    let look_ahead = executor.look_ahead();
+                                    let q = $graphql_struct::build_query(look_ahead.arguments(), &look_ahead)?;
+                                    let item = $graphql_struct::load_by_unique_field(field, &look_ahead, self.1, executor, q)?;
+                                    Ok(item.unwrap_or($crate::juniper::Value::Null))
+                                }
                             )*
                                 e => Err($crate::juniper::FieldError::new(
                                     "Unknown field:",
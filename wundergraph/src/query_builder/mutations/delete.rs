@@ -59,7 +59,7 @@ where
 /// * `L`: Struct implementing `LoadingHandler`
 /// * `K`: Input type used determine which entities should be deleted.
 ///    Normally something representing the primary key of the table
-/// * `DB`: Backend type from diesel, so one of `Pg` or `Sqlite`
+/// * `DB`: Backend type from diesel, so one of `Pg`, `Sqlite` or `Mysql`
 /// * `Ctx`: The used wundergraph context type
 pub trait HandleDelete<L, K, DB, Ctx> {
     /// Actual function called to delete a database entity
@@ -15,6 +15,12 @@ mod pg;
 #[cfg(feature = "sqlite")]
 mod sqlite;
 
+#[cfg(feature = "mysql")]
+mod mysql;
+
+#[cfg(feature = "mysql")]
+pub use self::mysql::InsertedKey;
+
 #[doc(hidden)]
 pub fn handle_insert<DB, I, R, Ctx>(
     selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
@@ -78,7 +84,7 @@ where
 /// Type parameters:
 /// * `Self`: database table type for diesel
 /// * `I`: data to insert into the table
-/// * `DB`: Backend type from diesel, so one of `Pg` or `Sqlite`
+/// * `DB`: Backend type from diesel, so one of `Pg`, `Sqlite` or `Mysql`
 /// * `Ctx`: The used wundergraph context type
 ///
 /// A default implementation is provided for all types implementing
@@ -97,7 +103,7 @@ pub trait HandleInsert<L, I, DB, Ctx> {
 /// Type parameters:
 /// * `Self`: database table type for diesel
 /// * `I`: data to insert into the table
-/// * `DB`: Backend type from diesel, so one of `Pg` or `Sqlite`
+/// * `DB`: Backend type from diesel, so one of `Pg`, `Sqlite` or `Mysql`
 /// * `Ctx`: The used wundergraph context type
 ///
 /// A default implementation is provided for all types implementing
@@ -0,0 +1,183 @@
+use super::{HandleBatchInsert, HandleInsert};
+use crate::context::WundergraphContext;
+use crate::helper::UnRef;
+use crate::query_builder::selection::fields::WundergraphFieldList;
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::order::BuildOrder;
+use crate::query_builder::selection::query_modifier::QueryModifier;
+use crate::query_builder::selection::select::BuildSelect;
+use crate::query_builder::selection::{LoadingHandler, SqlTypeOfPlaceholder};
+use crate::scalar::WundergraphScalarValue;
+use diesel::associations::HasTable;
+use diesel::dsl::{Eq, SqlTypeOf};
+use diesel::expression::dsl::sql;
+use diesel::expression::{NonAggregate, SelectableExpression, SqlLiteral};
+use diesel::mysql::Mysql;
+use diesel::query_builder::{BoxedSelectStatement, InsertStatement, QueryFragment};
+use diesel::query_dsl::methods::{BoxedDsl, ExecuteDsl, FilterDsl};
+use diesel::sql_types::HasSqlType;
+use diesel::{AppearsOnTable, Connection, EqAll, ExpressionMethods, Identifiable};
+use diesel::{Insertable, RunQueryDsl, Table};
+use juniper::{ExecutionResult, Executor, FieldError, Selection, Value};
+
+/// A trait to provide the primary key supplied by an insertable
+///
+/// Mysql does not support `RETURNING`, so inserted entities are loaded
+/// by comparing the primary key with `LAST_INSERT_ID()`. This value is
+/// only set for generated `AUTO_INCREMENT` keys, therefore insertables
+/// that set the primary key themselves need to return that key here.
+///
+/// Insertables that never contain the primary key use the default
+/// implementation:
+/// ```rust,ignore
+/// impl InsertedKey<i32> for NewHero {}
+/// ```
+pub trait InsertedKey<Id> {
+    /// The primary key set by this insertable, if any
+    fn inserted_key(&self) -> Option<Id> {
+        None
+    }
+}
+
+type LastInsertId<T> =
+    Eq<<T as Table>::PrimaryKey, SqlLiteral<SqlTypeOf<<T as Table>::PrimaryKey>>>;
+
+fn last_insert_id<T>() -> LastInsertId<T>
+where
+    T: Table,
+    T::PrimaryKey: Default,
+{
+    T::PrimaryKey::default().eq(sql::<SqlTypeOf<T::PrimaryKey>>("LAST_INSERT_ID()"))
+}
+
+fn insert_and_load<I, Ctx, L, T, Id>(
+    selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+    executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    insertable: I,
+) -> Result<Vec<Value<WundergraphScalarValue>>, FieldError<WundergraphScalarValue>>
+where
+    T: Table + HasTable<Table = T> + 'static,
+    T::FromClause: QueryFragment<Mysql>,
+    T::PrimaryKey: EqAll<Id> + Default,
+    L: LoadingHandler<Mysql, Ctx, Table = T>,
+    L::Columns: BuildOrder<T, Mysql>
+        + BuildSelect<T, Mysql, SqlTypeOfPlaceholder<L::FieldList, Mysql, L::PrimaryKeyIndex, T, Ctx>>,
+    Ctx: WundergraphContext + QueryModifier<L, Mysql>,
+    Ctx::Connection: Connection<Backend = Mysql>,
+    L::FieldList: WundergraphFieldList<Mysql, L::PrimaryKeyIndex, T, Ctx>,
+    I: Insertable<T> + InsertedKey<Id>,
+    I::Values: QueryFragment<Mysql>,
+    InsertStatement<T, I::Values>: ExecuteDsl<Ctx::Connection>,
+    T: BoxedDsl<
+        'static,
+        Mysql,
+        Output = BoxedSelectStatement<'static, SqlTypeOf<<T as Table>::AllColumns>, T, Mysql>,
+    >,
+    <L::Filter as BuildFilter<Mysql>>::Ret: AppearsOnTable<T>,
+    LastInsertId<T>: AppearsOnTable<T> + NonAggregate + QueryFragment<Mysql> + 'static,
+    <T::PrimaryKey as EqAll<Id>>::Output:
+        SelectableExpression<T> + NonAggregate + QueryFragment<Mysql> + 'static,
+    Mysql: HasSqlType<SqlTypeOfPlaceholder<L::FieldList, Mysql, L::PrimaryKeyIndex, T, Ctx>>,
+{
+    let look_ahead = executor.look_ahead();
+    let key = insertable.inserted_key();
+    insertable
+        .insert_into(T::table())
+        .execute(executor.context().get_connection())?;
+    let q = L::build_query(&[], &look_ahead)?;
+    match key {
+        Some(key) => {
+            let q = FilterDsl::filter(q, T::PrimaryKey::default().eq_all(key));
+            Ok(L::load(&look_ahead, selection, executor, q)?)
+        }
+        None => {
+            let q = FilterDsl::filter(q, last_insert_id::<T>());
+            Ok(L::load(&look_ahead, selection, executor, q)?)
+        }
+    }
+}
+
+impl<I, Ctx, L, T, Id> HandleInsert<L, I, Mysql, Ctx> for T
+where
+    T: Table + HasTable<Table = T> + 'static,
+    T::FromClause: QueryFragment<Mysql>,
+    T::PrimaryKey: EqAll<Id> + Default,
+    L: LoadingHandler<Mysql, Ctx, Table = T> + 'static,
+    L::Columns: BuildOrder<T, Mysql>
+        + BuildSelect<T, Mysql, SqlTypeOfPlaceholder<L::FieldList, Mysql, L::PrimaryKeyIndex, T, Ctx>>,
+    Ctx: WundergraphContext + QueryModifier<L, Mysql>,
+    Ctx::Connection: Connection<Backend = Mysql>,
+    L::FieldList: WundergraphFieldList<Mysql, L::PrimaryKeyIndex, T, Ctx>,
+    I: Insertable<T> + InsertedKey<Id>,
+    I::Values: QueryFragment<Mysql>,
+    InsertStatement<T, I::Values>: ExecuteDsl<Ctx::Connection>,
+    T: BoxedDsl<
+        'static,
+        Mysql,
+        Output = BoxedSelectStatement<'static, SqlTypeOf<<T as Table>::AllColumns>, T, Mysql>,
+    >,
+    <L::Filter as BuildFilter<Mysql>>::Ret: AppearsOnTable<T>,
+    &'static L: Identifiable,
+    <&'static L as Identifiable>::Id: UnRef<'static, UnRefed = Id>,
+    LastInsertId<T>: AppearsOnTable<T> + NonAggregate + QueryFragment<Mysql> + 'static,
+    <T::PrimaryKey as EqAll<Id>>::Output:
+        SelectableExpression<T> + NonAggregate + QueryFragment<Mysql> + 'static,
+    Mysql: HasSqlType<SqlTypeOfPlaceholder<L::FieldList, Mysql, L::PrimaryKeyIndex, T, Ctx>>,
+{
+    fn handle_insert(
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        insertable: I,
+    ) -> ExecutionResult<WundergraphScalarValue> {
+        let conn = executor.context().get_connection();
+        conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
+            let items = insert_and_load::<_, _, L, T, Id>(selection, executor, insertable)?;
+            Ok(items.into_iter().next().unwrap_or(Value::Null))
+        })
+    }
+}
+
+impl<I, Ctx, L, T, Id> HandleBatchInsert<L, I, Mysql, Ctx> for T
+where
+    T: Table + HasTable<Table = T> + 'static,
+    T::FromClause: QueryFragment<Mysql>,
+    T::PrimaryKey: EqAll<Id> + Default,
+    L: LoadingHandler<Mysql, Ctx, Table = T> + 'static,
+    L::Columns: BuildOrder<T, Mysql>
+        + BuildSelect<T, Mysql, SqlTypeOfPlaceholder<L::FieldList, Mysql, L::PrimaryKeyIndex, T, Ctx>>,
+    Ctx: WundergraphContext + QueryModifier<L, Mysql>,
+    Ctx::Connection: Connection<Backend = Mysql>,
+    L::FieldList: WundergraphFieldList<Mysql, L::PrimaryKeyIndex, T, Ctx>,
+    I: Insertable<T> + InsertedKey<Id>,
+    I::Values: QueryFragment<Mysql>,
+    InsertStatement<T, I::Values>: ExecuteDsl<Ctx::Connection>,
+    T: BoxedDsl<
+        'static,
+        Mysql,
+        Output = BoxedSelectStatement<'static, SqlTypeOf<<T as Table>::AllColumns>, T, Mysql>,
+    >,
+    <L::Filter as BuildFilter<Mysql>>::Ret: AppearsOnTable<T>,
+    &'static L: Identifiable,
+    <&'static L as Identifiable>::Id: UnRef<'static, UnRefed = Id>,
+    LastInsertId<T>: AppearsOnTable<T> + NonAggregate + QueryFragment<Mysql> + 'static,
+    <T::PrimaryKey as EqAll<Id>>::Output:
+        SelectableExpression<T> + NonAggregate + QueryFragment<Mysql> + 'static,
+    Mysql: HasSqlType<SqlTypeOfPlaceholder<L::FieldList, Mysql, L::PrimaryKeyIndex, T, Ctx>>,
+{
+    fn handle_batch_insert(
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        batch: Vec<I>,
+    ) -> ExecutionResult<WundergraphScalarValue> {
+        let conn = executor.context().get_connection();
+        conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
+            // `LAST_INSERT_ID()` only returns the first id of a multi row
+            // insert, so each entity is inserted and loaded on its own
+            let items = batch
+                .into_iter()
+                .map(|i| insert_and_load::<_, _, L, T, Id>(selection, executor, i))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Value::list(items.into_iter().flatten().collect()))
+        })
+    }
+}
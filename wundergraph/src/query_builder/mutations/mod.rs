@@ -73,6 +73,9 @@ mod update;
 
 #[doc(inline)]
 pub use self::delete::{DeletedCount, HandleDelete};
+#[cfg(feature = "mysql")]
+#[doc(inline)]
+pub use self::insert::InsertedKey;
 #[doc(inline)]
 pub use self::insert::{HandleBatchInsert, HandleInsert};
 #[doc(inline)]
@@ -54,7 +54,7 @@ where
 /// * `U`: data to update into the table. This type should contain information
 ///    which entry should be updated (by containing the primary key) and the data
 ///    that should be actually updated
-/// * `DB`: Backend type from diesel, so one of `Pg` or `Sqlite`
+/// * `DB`: Backend type from diesel, so one of `Pg`, `Sqlite` or `Mysql`
 /// * `Ctx`: The used wundergraph context type
 ///
 /// A default implementation is provided for all types implementing
@@ -0,0 +1,868 @@
+//! This module contains the types used to expose aggregates of an entity
+//!
+//! An `{name}Aggregate` field is registered by `query_object!` for each
+//! entity marked with `#[wundergraph(aggregate = true)]`. It returns an
+//! object containing the number of entities matching the `filter` argument
+//! and one object per aggregate function containing a field for each
+//! field of the entity that could be aggregated by this function:
+//!
+//! ```graphql
+//! {
+//!     HerosAggregate(filter: {heroName: {like: "%a%"}}) {
+//!         count
+//!         sum { height }
+//!         avg { height }
+//!         min { height }
+//!         max { height }
+//!     }
+//! }
+//! ```
+//!
+//! `sum` and `avg` are supported for numeric fields and always return a
+//! `Float`, `min` and `max` are supported for numeric and temporal fields
+//! and return the type of the field. The count and all requested values
+//! are computed by a single query.
+//!
+//! The `groups` field groups the matching entities by the columns given by
+//! the `groupBy` argument, which accepts the values of the `{name}Columns`
+//! enum used to order entities. It returns the values of the grouping
+//! columns as `key` together with the aggregates of each group:
+//!
+//! ```graphql
+//! {
+//!     HerosAggregate {
+//!         groups(groupBy: [species]) {
+//!             key { species { name } }
+//!             count
+//!             avg { height }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Groups are ordered by the grouping columns and loaded by one further
+//! query. Only fields backed by a
+//! column of the entity could be used for grouping, `key` may only contain
+//! those fields.
+
+use super::fields::WundergraphFieldList;
+use super::filter::build_filter::BuildFilter;
+use super::offset::ApplyOffset;
+use super::order::GraphqlOrderWrapper;
+use super::select::BuildSelect;
+use super::{BoxedQuery, LoadingHandler, QueryModifier, SqlTypeOfPlaceholder};
+use crate::error::Result;
+use crate::error::WundergraphError;
+use crate::graphql_type::GraphqlWrapper;
+use crate::scalar::WundergraphScalarValue;
+use diesel::associations::HasTable;
+use diesel::backend::Backend;
+use diesel::dsl::SqlTypeOf;
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use diesel::query_builder::{AstPass, BoxedSelectStatement, Query, QueryFragment, QueryId};
+use diesel::query_dsl::methods::{BoxedDsl, SelectDsl};
+use diesel::sql_types::{Double, HasSqlType, IntoNullable, Nullable};
+use diesel::{
+    BoxableExpression, Column, Connection, QueryDsl, QueryResult, QuerySource, Queryable,
+    RunQueryDsl, Table,
+};
+use juniper::parser::Spanning;
+use juniper::{
+    meta, Executor, FromContext, GraphQLType, LookAheadArgument, LookAheadMethods,
+    LookAheadSelection, LookAheadValue, Object, Registry, Selection, Value,
+};
+use std::marker::PhantomData;
+
+/// An aggregate function applied to a field of an entity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AggregateFunction {
+    /// The sum of all values
+    Sum,
+    /// The average of all values
+    Avg,
+    /// The smallest value
+    Min,
+    /// The largest value
+    Max,
+}
+
+impl AggregateFunction {
+    /// All supported aggregate functions
+    pub const ALL: [AggregateFunction; 4] = [
+        AggregateFunction::Sum,
+        AggregateFunction::Avg,
+        AggregateFunction::Min,
+        AggregateFunction::Max,
+    ];
+
+    /// The name of the field returning the results of this function
+    pub fn field_name(self) -> &'static str {
+        match self {
+            AggregateFunction::Sum => "sum",
+            AggregateFunction::Avg => "avg",
+            AggregateFunction::Min => "min",
+            AggregateFunction::Max => "max",
+        }
+    }
+
+    /// Returns the function for a name returned by `field_name`
+    pub fn from_field_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().cloned().find(|f| f.field_name() == name)
+    }
+
+    fn sql_function(self) -> &'static str {
+        match self {
+            AggregateFunction::Sum => "SUM",
+            AggregateFunction::Avg => "AVG",
+            AggregateFunction::Min => "MIN",
+            AggregateFunction::Max => "MAX",
+        }
+    }
+
+    pub(crate) fn type_name_suffix(self) -> &'static str {
+        match self {
+            AggregateFunction::Sum => "SumFields",
+            AggregateFunction::Avg => "AvgFields",
+            AggregateFunction::Min => "MinFields",
+            AggregateFunction::Max => "MaxFields",
+        }
+    }
+
+    // The result of `SUM` and `AVG` depends on the column type and the
+    // backend, so it is cast to a double precision value
+    fn is_cast_to_double(self) -> bool {
+        match self {
+            AggregateFunction::Sum | AggregateFunction::Avg => true,
+            AggregateFunction::Min | AggregateFunction::Max => false,
+        }
+    }
+}
+
+/// Applies an aggregate function to the column `C`
+///
+/// Compiles down to `MIN(column)` or `MAX(column)` and to
+/// `CAST(SUM(column) AS double)` or `CAST(AVG(column) AS double)`,
+/// aggregates that are not requested compile down to `NULL`
+#[derive(Debug)]
+pub struct Aggregate<C, ST> {
+    function: Option<AggregateFunction>,
+    p: PhantomData<(C, ST)>,
+}
+
+impl<C, ST> Aggregate<C, ST> {
+    fn new(
+        function: AggregateFunction,
+        name: &str,
+        requested: &[(AggregateFunction, &str)],
+    ) -> Self {
+        Self {
+            function: if requested.contains(&(function, name)) {
+                Some(function)
+            } else {
+                None
+            },
+            p: PhantomData,
+        }
+    }
+}
+
+impl<C, ST> Expression for Aggregate<C, ST> {
+    type SqlType = ST;
+}
+
+impl<C, ST, QS> AppearsOnTable<QS> for Aggregate<C, ST> where C: AppearsOnTable<QS> {}
+
+impl<C, ST, QS> SelectableExpression<QS> for Aggregate<C, ST> where C: SelectableExpression<QS> {}
+
+impl<C, ST> QueryId for Aggregate<C, ST> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+fn walk_aggregate<C, DB>(
+    function: Option<AggregateFunction>,
+    double: &str,
+    mut pass: AstPass<'_, DB>,
+) -> QueryResult<()>
+where
+    DB: Backend,
+    C: QueryFragment<DB> + Default,
+{
+    let function = match function {
+        Some(function) => function,
+        None => {
+            pass.push_sql("NULL");
+            return Ok(());
+        }
+    };
+    if function.is_cast_to_double() {
+        pass.push_sql("CAST(");
+    }
+    pass.push_sql(function.sql_function());
+    pass.push_sql("(");
+    C::default().walk_ast(pass.reborrow())?;
+    pass.push_sql(")");
+    if function.is_cast_to_double() {
+        pass.push_sql(" AS ");
+        pass.push_sql(double);
+        pass.push_sql(")");
+    }
+    Ok(())
+}
+
+#[cfg(feature = "postgres")]
+impl<C, ST> QueryFragment<diesel::pg::Pg> for Aggregate<C, ST>
+where
+    C: QueryFragment<diesel::pg::Pg> + Default,
+{
+    fn walk_ast(&self, pass: AstPass<'_, diesel::pg::Pg>) -> QueryResult<()> {
+        walk_aggregate::<C, _>(self.function, "DOUBLE PRECISION", pass)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<C, ST> QueryFragment<diesel::sqlite::Sqlite> for Aggregate<C, ST>
+where
+    C: QueryFragment<diesel::sqlite::Sqlite> + Default,
+{
+    fn walk_ast(&self, pass: AstPass<'_, diesel::sqlite::Sqlite>) -> QueryResult<()> {
+        walk_aggregate::<C, _>(self.function, "REAL", pass)
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<C, ST> QueryFragment<diesel::mysql::Mysql> for Aggregate<C, ST>
+where
+    C: QueryFragment<diesel::mysql::Mysql> + Default,
+{
+    fn walk_ast(&self, pass: AstPass<'_, diesel::mysql::Mysql>) -> QueryResult<()> {
+        walk_aggregate::<C, _>(self.function, "DOUBLE", pass)
+    }
+}
+
+type MinMaxSqlType<C> = <<C as Expression>::SqlType as IntoNullable>::Nullable;
+
+/// Selects the expressions `A` and `B` next to each other
+///
+/// Compiles down to `a, b` and is loaded as a tuple. Diesel only selects
+/// tuples of non aggregate expressions, nesting `AggregateSelect` allows to
+/// load the key of a group together with several aggregates by a single
+/// query.
+#[derive(Debug, Clone, Copy)]
+pub struct AggregateSelect<A, B>(A, B);
+
+impl<A, B> AggregateSelect<A, B> {
+    /// Select `a` followed by `b`
+    pub fn new(a: A, b: B) -> Self {
+        Self(a, b)
+    }
+}
+
+impl<A, B> Expression for AggregateSelect<A, B>
+where
+    A: Expression,
+    B: Expression,
+{
+    type SqlType = (A::SqlType, B::SqlType);
+}
+
+impl<A, B, QS> AppearsOnTable<QS> for AggregateSelect<A, B>
+where
+    A: AppearsOnTable<QS>,
+    B: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+impl<A, B, QS> SelectableExpression<QS> for AggregateSelect<A, B>
+where
+    A: SelectableExpression<QS>,
+    B: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+// Like a tuple the pair is only an aggregate if one of its elements is
+impl<A, B> NonAggregate for AggregateSelect<A, B>
+where
+    A: NonAggregate,
+    B: NonAggregate,
+    Self: Expression,
+{
+}
+
+impl<A, B> QueryId for AggregateSelect<A, B> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<A, B, DB> QueryFragment<DB> for AggregateSelect<A, B>
+where
+    DB: Backend,
+    A: QueryFragment<DB>,
+    B: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, DB>) -> QueryResult<()> {
+        self.0.walk_ast(pass.reborrow())?;
+        pass.push_sql(", ");
+        self.1.walk_ast(pass)
+    }
+}
+
+/// The aggregates of the column `C` selected by `AggregateColumn::select`
+pub type AggregateColumnSelect<C> = AggregateSelect<
+    AggregateSelect<Aggregate<C, Nullable<Double>>, Aggregate<C, Nullable<Double>>>,
+    AggregateSelect<Aggregate<C, MinMaxSqlType<C>>, Aggregate<C, MinMaxSqlType<C>>>,
+>;
+
+/// The values loaded for `AggregateColumnSelect`, in the order
+/// `((sum, avg), (min, max))`
+pub type AggregateColumnValues<T> = ((Option<f64>, Option<f64>), (Option<T>, Option<T>));
+
+/// The key, the number of entities and the requested aggregates of a group
+/// loaded by `LoadingHandler::load_aggregate_values`
+#[allow(missing_debug_implementations)]
+pub struct AggregateRow<K> {
+    /// The placeholder holding the values of the grouping columns
+    pub key: K,
+    /// The number of entities in this group
+    pub count: i64,
+    /// The value of each requested aggregate, in the order of the request
+    pub values: Vec<Value<WundergraphScalarValue>>,
+}
+
+/// The placeholder type holding the key columns of a group of entities
+pub type GroupKey<L, DB, Ctx> =
+    <<L as LoadingHandler<DB, Ctx>>::FieldList as WundergraphFieldList<
+        DB,
+        <L as LoadingHandler<DB, Ctx>>::PrimaryKeyIndex,
+        <L as HasTable>::Table,
+        Ctx,
+    >>::PlaceHolder;
+
+/// The sql type of `GroupKey`
+pub type GroupKeySqlType<L, DB, Ctx> = SqlTypeOfPlaceholder<
+    <L as LoadingHandler<DB, Ctx>>::FieldList,
+    DB,
+    <L as LoadingHandler<DB, Ctx>>::PrimaryKeyIndex,
+    <L as HasTable>::Table,
+    Ctx,
+>;
+
+/// Groups the rows of a query by columns of its select clause and orders
+/// the groups by the same columns
+///
+/// Compiles down to `query GROUP BY 1, 3 ORDER BY 1, 3`, where the numbers
+/// refer to the positions of the columns in the select clause. The query
+/// is left unchanged if there are no columns, so it aggregates all rows.
+/// `query` must not contain an order, limit or offset clause.
+#[allow(missing_debug_implementations)]
+pub(crate) struct GroupBy<Q> {
+    query: Q,
+    columns: Vec<usize>,
+}
+
+impl<Q> GroupBy<Q> {
+    pub(crate) fn new(query: Q, columns: Vec<usize>) -> Self {
+        Self { query, columns }
+    }
+}
+
+impl<Q> Query for GroupBy<Q>
+where
+    Q: Query,
+{
+    type SqlType = Q::SqlType;
+}
+
+impl<Q> QueryId for GroupBy<Q> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<Q, DB> QueryFragment<DB> for GroupBy<Q>
+where
+    DB: Backend,
+    Q: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, DB>) -> QueryResult<()> {
+        self.query.walk_ast(pass.reborrow())?;
+        if self.columns.is_empty() {
+            return Ok(());
+        }
+        for clause in &[" GROUP BY ", " ORDER BY "] {
+            pass.push_sql(clause);
+            for (i, column) in self.columns.iter().enumerate() {
+                if i > 0 {
+                    pass.push_sql(", ");
+                }
+                pass.push_sql(&(column + 1).to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<Q, Conn> RunQueryDsl<Conn> for GroupBy<Q> {}
+
+/// Selects the entities aggregated by `LoadingHandler::load_aggregate_values`
+///
+/// Contains the filtered query of the `{name}Aggregate` field and the
+/// columns given by the `groupBy` argument. Loading an expression returns
+/// one row per group, in the order of the groups.
+#[allow(missing_debug_implementations)]
+pub struct AggregateQuery<'a, L, DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+    L: LoadingHandler<DB, Ctx>,
+{
+    query: BoxedQuery<'a, L, DB, Ctx>,
+    key: Box<dyn BoxableExpression<L::Table, DB, SqlType = GroupKeySqlType<L, DB, Ctx>>>,
+    group_by: Vec<usize>,
+}
+
+impl<'a, L, DB, Ctx> AggregateQuery<'a, L, DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+    L: LoadingHandler<DB, Ctx>,
+{
+    /// Build the query for the entities matching the filter of `select`
+    /// grouped by the table fields at the local indices `group_by`
+    ///
+    /// Applies the `QueryModifier` of the context
+    pub fn new(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        group_by: &[usize],
+        ctx: &Ctx,
+    ) -> Result<Self>
+    where
+        L::Table: BoxedDsl<
+                'a,
+                DB,
+                Output = BoxedSelectStatement<
+                    'a,
+                    SqlTypeOf<<L::Table as Table>::AllColumns>,
+                    L::Table,
+                    DB,
+                >,
+            > + 'static,
+        <L::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<L::Table>,
+        Ctx: QueryModifier<L, DB>,
+    {
+        let query = <_ as SelectDsl<_>>::select(L::table().into_boxed(), L::get_select(select)?);
+        let query = L::apply_filter(query, select)?;
+        let query = ctx.modify_query(select, query)?;
+        // Only the grouping columns are selected, all other columns
+        // of the key are `NULL`
+        let key = <L::Columns as BuildSelect<L::Table, DB, _>>::build_select(
+            select,
+            |_| "",
+            |index| group_by.contains(&index),
+            true,
+        )?;
+        Ok(Self {
+            query,
+            key,
+            group_by: group_by.to_vec(),
+        })
+    }
+
+    /// Load the value of `expr` for each group together with its key
+    ///
+    /// Use `AggregateSelect` to load several aggregates at once
+    pub fn load<E, R>(
+        self,
+        expr: E,
+        conn: &impl Connection<Backend = DB>,
+    ) -> Result<Vec<(GroupKey<L, DB, Ctx>, R)>>
+    where
+        E: SelectableExpression<L::Table> + QueryFragment<DB> + 'a,
+        DB: HasSqlType<(GroupKeySqlType<L, DB, Ctx>, E::SqlType)>,
+        <L::Table as QuerySource>::FromClause: QueryFragment<DB>,
+        (GroupKey<L, DB, Ctx>, R): Queryable<(GroupKeySqlType<L, DB, Ctx>, E::SqlType), DB>,
+    {
+        let query = GroupBy {
+            query: <_ as SelectDsl<_>>::select(self.query, AggregateSelect(self.key, expr)),
+            columns: self.group_by,
+        };
+        #[cfg(feature = "debug")]
+        {
+            log::debug!("{:?}", diesel::debug_query(&query));
+        }
+        Ok(query.load(conn)?)
+    }
+}
+
+/// Aggregates the column `C` backing a field of the type `T`
+///
+/// `T` is the type of the field without a surrounding `Option`.
+/// Used by `#[derive(WundergraphEntity)]` to implement
+/// `LoadingHandler::aggregate_fields` and
+/// `LoadingHandler::load_aggregate_values` for each field with a numeric or
+/// temporal type, which is not part of the primary key
+#[derive(Debug)]
+pub struct AggregateColumn<C, T>(PhantomData<(C, T)>);
+
+impl<C, T> AggregateColumn<C, T>
+where
+    C: Column + Default + SelectableExpression<C::Table> + 'static,
+    C::SqlType: IntoNullable,
+    T: GraphQLType<WundergraphScalarValue, TypeInfo = ()>,
+{
+    /// Register the field `name` of the object returned for `function`
+    pub fn register_field<'r>(
+        function: AggregateFunction,
+        name: &str,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> meta::Field<'r, WundergraphScalarValue> {
+        if function.is_cast_to_double() {
+            registry.field::<Option<f64>>(name, &())
+        } else {
+            registry.field::<Option<T>>(name, &())
+        }
+    }
+
+    /// Select the aggregates of the column for the field `name`
+    ///
+    /// Aggregates not contained in `requested` are not computed
+    pub fn select(name: &str, requested: &[(AggregateFunction, &str)]) -> AggregateColumnSelect<C> {
+        AggregateSelect(
+            AggregateSelect(
+                Aggregate::new(AggregateFunction::Sum, name, requested),
+                Aggregate::new(AggregateFunction::Avg, name, requested),
+            ),
+            AggregateSelect(
+                Aggregate::new(AggregateFunction::Min, name, requested),
+                Aggregate::new(AggregateFunction::Max, name, requested),
+            ),
+        )
+    }
+
+    /// Resolve the aggregates of the field `name` loaded for `select`
+    ///
+    /// `values` contains one entry per element of `requested`, only the
+    /// entries requested for `name` are set
+    pub fn resolve<Ctx>(
+        name: &str,
+        loaded: AggregateColumnValues<T>,
+        requested: &[(AggregateFunction, &str)],
+        values: &mut [Value<WundergraphScalarValue>],
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<()>
+    where
+        T::Context: FromContext<Ctx>,
+    {
+        let ((sum, avg), (min, max)) = loaded;
+        for (value, (function, field)) in values.iter_mut().zip(requested) {
+            if *field != name {
+                continue;
+            }
+            *value = match function {
+                AggregateFunction::Sum => sum.map_or_else(Value::null, Value::scalar),
+                AggregateFunction::Avg => avg.map_or_else(Value::null, Value::scalar),
+                AggregateFunction::Min => executor
+                    .resolve_with_ctx(&(), &min)
+                    .map_err(|inner| WundergraphError::JuniperError { inner })?,
+                AggregateFunction::Max => executor
+                    .resolve_with_ctx(&(), &max)
+                    .map_err(|inner| WundergraphError::JuniperError { inner })?,
+            };
+        }
+        Ok(())
+    }
+}
+
+/// Build an object out of the fields requested by `selection`
+///
+/// Fields for which `field` returns `None` are skipped
+pub(crate) fn try_build_object<'a>(
+    selection: Option<&'a [Selection<'a, WundergraphScalarValue>]>,
+    mut field: impl FnMut(
+        &str,
+        Option<&'a [Selection<'a, WundergraphScalarValue>]>,
+    ) -> Result<Option<Value<WundergraphScalarValue>>>,
+) -> Result<Value<WundergraphScalarValue>> {
+    let selection = selection.unwrap_or(&[]);
+    let mut obj = Object::with_capacity(selection.len());
+    for s in selection {
+        if let Selection::Field(Spanning { item: ref f, .. }) = *s {
+            let selection = f.selection_set.as_ref().map(|s| s as _);
+            if let Some(value) = field(f.name.item, selection)? {
+                obj.add_field(f.alias.unwrap_or(f.name).item, value);
+            }
+        }
+    }
+    Ok(Value::Object(obj))
+}
+
+/// Collect the fields requested from the objects returned for each
+/// aggregate function, as pairs of the function and the field name
+pub(crate) fn requested_aggregates<'a>(
+    selection: Option<&'a [Selection<'a, WundergraphScalarValue>]>,
+) -> Vec<(AggregateFunction, &'a str)> {
+    let mut ret: Vec<(AggregateFunction, &str)> = Vec::new();
+    for s in selection.unwrap_or(&[]) {
+        if let Selection::Field(Spanning { item: ref f, .. }) = *s {
+            let function = match AggregateFunction::from_field_name(f.name.item) {
+                Some(function) => function,
+                None => continue,
+            };
+            for s in f.selection_set.as_ref().map_or(&[][..], |s| &s[..]) {
+                if let Selection::Field(Spanning { item: ref f, .. }) = *s {
+                    let field = f.name.item;
+                    if field != "__typename" && !ret.contains(&(function, field)) {
+                        ret.push((function, field));
+                    }
+                }
+            }
+        }
+    }
+    ret
+}
+
+/// Collect the local indices of the table fields given by the `groupBy`
+/// argument of a `groups` field
+///
+/// Only fields backed by a column of the current table are supported
+pub(crate) fn group_by_columns<L, DB, Ctx>(
+    select: &LookAheadSelection<'_, WundergraphScalarValue>,
+) -> Result<Vec<usize>>
+where
+    DB: Backend + ApplyOffset + 'static,
+    L: LoadingHandler<DB, Ctx>,
+{
+    let field_index = |name: &str| {
+        (0..L::FieldList::TABLE_FIELD_COUNT).find(|i| {
+            L::FieldList::map_table_field(*i, |global| L::FIELD_NAMES[global] == name)
+                .unwrap_or(false)
+        })
+    };
+    let columns = match select.argument("groupBy").map(LookAheadArgument::value) {
+        Some(LookAheadValue::List(columns)) => columns,
+        _ => {
+            return Err(WundergraphError::InvalidGroupByArgument {
+                path: String::from("groupBy"),
+            })
+        }
+    };
+    let mut ret = Vec::with_capacity(columns.len());
+    for (idx, column) in columns.iter().enumerate() {
+        let index = if let LookAheadValue::Enum(c) = column {
+            field_index(c)
+        } else {
+            None
+        }
+        .ok_or_else(|| WundergraphError::InvalidGroupByArgument {
+            path: format!("groupBy[{}]", idx),
+        })?;
+        if !ret.contains(&index) {
+            ret.push(index);
+        }
+    }
+    Ok(ret)
+}
+
+/// The aggregates of all entities matching a filter
+#[derive(Debug)]
+pub struct Aggregates<T, DB, Ctx>(PhantomData<(T, DB, Ctx)>);
+
+/// The key and the aggregates of a group of entities
+#[derive(Debug)]
+pub struct AggregateGroup<T, DB, Ctx>(PhantomData<(T, DB, Ctx)>);
+
+/// The results of an aggregate function for each field of an entity
+#[derive(Debug)]
+pub struct AggregateFields<T, DB, Ctx>(PhantomData<(T, DB, Ctx)>);
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct AggregatesTypeInfo<T, DB, Ctx>(String, PhantomData<(T, DB, Ctx)>);
+
+impl<T, DB, Ctx> Default for AggregatesTypeInfo<T, DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+    T::Table: 'static,
+    <T::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    T: LoadingHandler<DB, Ctx>,
+    DB::QueryBuilder: Default,
+{
+    fn default() -> Self {
+        Self(format!("{}Aggregate", T::TYPE_NAME), PhantomData)
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct AggregateGroupTypeInfo<T, DB, Ctx>(String, PhantomData<(T, DB, Ctx)>);
+
+impl<T, DB, Ctx> Default for AggregateGroupTypeInfo<T, DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+    T::Table: 'static,
+    <T::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    T: LoadingHandler<DB, Ctx>,
+    DB::QueryBuilder: Default,
+{
+    fn default() -> Self {
+        Self(format!("{}AggregateGroup", T::TYPE_NAME), PhantomData)
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct AggregateFieldsTypeInfo<T, DB, Ctx>(
+    String,
+    AggregateFunction,
+    PhantomData<(T, DB, Ctx)>,
+);
+
+impl<T, DB, Ctx> AggregateFieldsTypeInfo<T, DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+    T::Table: 'static,
+    <T::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    T: LoadingHandler<DB, Ctx>,
+    DB::QueryBuilder: Default,
+{
+    fn new(function: AggregateFunction) -> Self {
+        Self(
+            format!("{}{}", T::TYPE_NAME, function.type_name_suffix()),
+            function,
+            PhantomData,
+        )
+    }
+}
+
+impl<T, DB, Ctx> GraphQLType<WundergraphScalarValue> for Aggregates<T, DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+    T::Table: 'static,
+    <T::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    T: LoadingHandler<DB, Ctx>,
+    DB::QueryBuilder: Default,
+    GraphqlOrderWrapper<T, DB, Ctx>: GraphQLType<WundergraphScalarValue>,
+    <GraphqlOrderWrapper<T, DB, Ctx> as GraphQLType<WundergraphScalarValue>>::TypeInfo: Default,
+    GraphqlWrapper<T, DB, Ctx>: GraphQLType<WundergraphScalarValue, TypeInfo = ()>,
+{
+    type Context = ();
+    type TypeInfo = AggregatesTypeInfo<T, DB, Ctx>;
+
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        Some(&info.0)
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> meta::MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let group_by =
+            registry.arg::<Vec<GraphqlOrderWrapper<T, DB, Ctx>>>("groupBy", &Default::default());
+        let mut fields = vec![
+            registry
+                .field::<i64>("count", &())
+                .description("Number of entities matching the filter"),
+            registry
+                .field::<Vec<AggregateGroup<T, DB, Ctx>>>("groups", &Default::default())
+                .argument(group_by)
+                .description("Aggregates of the entities grouped by the given columns"),
+        ];
+        fields.extend(aggregate_function_fields::<T, DB, Ctx>(registry));
+        registry
+            .build_object_type::<Self>(info, &fields)
+            .into_meta()
+    }
+}
+
+impl<T, DB, Ctx> GraphQLType<WundergraphScalarValue> for AggregateGroup<T, DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+    T::Table: 'static,
+    <T::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    T: LoadingHandler<DB, Ctx>,
+    DB::QueryBuilder: Default,
+    GraphqlWrapper<T, DB, Ctx>: GraphQLType<WundergraphScalarValue, TypeInfo = ()>,
+{
+    type Context = ();
+    type TypeInfo = AggregateGroupTypeInfo<T, DB, Ctx>;
+
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        Some(&info.0)
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> meta::MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let mut fields = vec![
+            registry
+                .field::<GraphqlWrapper<T, DB, Ctx>>("key", &())
+                .description("Values of the grouping columns"),
+            registry
+                .field::<i64>("count", &())
+                .description("Number of entities in this group"),
+        ];
+        fields.extend(aggregate_function_fields::<T, DB, Ctx>(registry));
+        registry
+            .build_object_type::<Self>(info, &fields)
+            .into_meta()
+    }
+}
+
+fn aggregate_function_fields<'r, T, DB, Ctx>(
+    registry: &mut Registry<'r, WundergraphScalarValue>,
+) -> Vec<meta::Field<'r, WundergraphScalarValue>>
+where
+    DB: Backend + ApplyOffset + 'static,
+    T::Table: 'static,
+    <T::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    T: LoadingHandler<DB, Ctx>,
+    DB::QueryBuilder: Default,
+{
+    let mut fields = Vec::new();
+    for function in &AggregateFunction::ALL {
+        // GraphQL objects require at least one field
+        if T::aggregate_fields(*function, registry).is_empty() {
+            continue;
+        }
+        fields.push(registry.field::<AggregateFields<T, DB, Ctx>>(
+            function.field_name(),
+            &AggregateFieldsTypeInfo::new(*function),
+        ));
+    }
+    fields
+}
+
+impl<T, DB, Ctx> GraphQLType<WundergraphScalarValue> for AggregateFields<T, DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+    T::Table: 'static,
+    <T::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    T: LoadingHandler<DB, Ctx>,
+    DB::QueryBuilder: Default,
+{
+    type Context = ();
+    type TypeInfo = AggregateFieldsTypeInfo<T, DB, Ctx>;
+
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        Some(&info.0)
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> meta::MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let fields = T::aggregate_fields(info.1, registry);
+        registry
+            .build_object_type::<Self>(info, &fields)
+            .into_meta()
+    }
+}
@@ -0,0 +1,864 @@
+//! This module contains the types used to expose an entity as
+//! [relay connection](https://relay.dev/graphql/connections.htm)
+//!
+//! A connection field is registered by `query_object!` for each entity
+//! marked with `#[wundergraph(connection = true)]` or
+//! `#[wundergraph(connection = keyset)]`. Cursors are opaque strings.
+//! For `connection = true` they encode the position of an entity in the
+//! filtered and ordered result, for `connection = keyset` they encode the
+//! values of the order columns and the primary key of an entity.
+
+use super::limit::LimitPolicy;
+use super::offset::ApplyOffset;
+use super::order::Order;
+use super::LoadingHandler;
+use crate::error::{Result, WundergraphError};
+use crate::graphql_type::{GraphqlWrapper, WundergraphGraphqlHelper};
+use crate::helper::tuple::IsPrimaryKeyIndex;
+use crate::juniper_ext::FromLookAheadValue;
+use crate::query_builder::selection::fields::WundergraphFieldList;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::query_builder::QueryFragment;
+use diesel::QuerySource;
+use juniper::parser::Spanning;
+use juniper::{
+    meta, Executor, GraphQLType, LookAheadArgument, LookAheadMethods, LookAheadSelection,
+    LookAheadValue, Object, Registry, Selection, Value,
+};
+use std::marker::PhantomData;
+
+const CURSOR_PREFIX: &str = "offset:";
+const KEYSET_CURSOR_PREFIX: &str = "keyset:";
+static IS_NULL: [WundergraphScalarValue; 2] = [
+    WundergraphScalarValue::Boolean(false),
+    WundergraphScalarValue::Boolean(true),
+];
+
+/// Encode the position of an entity as cursor
+pub fn encode_cursor(position: i64) -> String {
+    base64::encode(format!("{}{}", CURSOR_PREFIX, position))
+}
+
+/// Decode a cursor returned by `encode_cursor`
+///
+/// Returns `None` if the given string is not a valid cursor
+pub fn decode_cursor(cursor: &str) -> Option<i64> {
+    let decoded = String::from_utf8(base64::decode(cursor).ok()?).ok()?;
+    if decoded.starts_with(CURSOR_PREFIX) {
+        decoded[CURSOR_PREFIX.len()..]
+            .parse()
+            .ok()
+            .filter(|p| *p >= 0)
+    } else {
+        None
+    }
+}
+
+fn encode_keyset_value(value: &Value<WundergraphScalarValue>) -> Option<String> {
+    Some(match value {
+        Value::Null => String::from("n"),
+        Value::Scalar(WundergraphScalarValue::SmallInt(v)) => format!("h{}", v),
+        Value::Scalar(WundergraphScalarValue::Int(v)) => format!("i{}", v),
+        Value::Scalar(WundergraphScalarValue::BigInt(v)) => format!("l{}", v),
+        Value::Scalar(WundergraphScalarValue::Float(v)) => format!("f{}", v),
+        Value::Scalar(WundergraphScalarValue::Double(v)) => format!("d{}", v),
+        Value::Scalar(WundergraphScalarValue::Boolean(v)) => format!("b{}", v),
+        Value::Scalar(WundergraphScalarValue::String(v)) => format!("s{}", base64::encode(v)),
+        Value::List(_) | Value::Object(_) => return None,
+    })
+}
+
+fn decode_keyset_value(value: &str) -> Option<Option<WundergraphScalarValue>> {
+    if value == "n" {
+        return Some(None);
+    }
+    let (tag, value) = (value.get(..1)?, &value[1..]);
+    Some(Some(match tag {
+        "h" => WundergraphScalarValue::SmallInt(value.parse().ok()?),
+        "i" => WundergraphScalarValue::Int(value.parse().ok()?),
+        "l" => WundergraphScalarValue::BigInt(value.parse().ok()?),
+        "f" => WundergraphScalarValue::Float(value.parse().ok()?),
+        "d" => WundergraphScalarValue::Double(value.parse().ok()?),
+        "b" => WundergraphScalarValue::Boolean(value.parse().ok()?),
+        "s" => WundergraphScalarValue::String(String::from_utf8(base64::decode(value).ok()?).ok()?),
+        _ => return None,
+    }))
+}
+
+/// Encode the values of the keyset columns of an entity as cursor
+///
+/// `values` contains the resolved value of each column in `columns`,
+/// returns an error if one of those could not be encoded
+pub fn encode_keyset_cursor(
+    columns: &[KeysetColumn],
+    values: &[Option<Value<WundergraphScalarValue>>],
+) -> Result<String> {
+    let parts = columns
+        .iter()
+        .zip(values)
+        .map(|(column, value)| {
+            value
+                .as_ref()
+                .and_then(encode_keyset_value)
+                .map(|v| format!("{}={}", column.name, v))
+                .ok_or_else(|| WundergraphError::UnsupportedKeysetColumn {
+                    name: column.name.to_owned(),
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(base64::encode(format!(
+        "{}{}",
+        KEYSET_CURSOR_PREFIX,
+        parts.join(",")
+    )))
+}
+
+/// Decode a cursor returned by `encode_keyset_cursor`
+///
+/// Returns `None` if the given string is not a valid cursor for the
+/// given keyset columns. `NULL` values are returned as `None`
+pub fn decode_keyset_cursor(
+    cursor: &str,
+    columns: &[KeysetColumn],
+) -> Option<Vec<Option<WundergraphScalarValue>>> {
+    let decoded = String::from_utf8(base64::decode(cursor).ok()?).ok()?;
+    if !decoded.starts_with(KEYSET_CURSOR_PREFIX) {
+        return None;
+    }
+    let parts = decoded[KEYSET_CURSOR_PREFIX.len()..]
+        .split(',')
+        .collect::<Vec<_>>();
+    if parts.len() != columns.len() {
+        return None;
+    }
+    parts
+        .into_iter()
+        .zip(columns)
+        .map(|(part, column)| {
+            let mut split = part.splitn(2, '=');
+            if split.next()? == column.name {
+                decode_keyset_value(split.next()?)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn count_argument(
+    select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    name: &str,
+) -> Result<Option<i64>> {
+    select
+        .argument(name)
+        .map(|v| {
+            i64::from_look_ahead(v.value())
+                .filter(|v| *v >= 0)
+                .ok_or_else(|| WundergraphError::InvalidPaginationArgument {
+                    name: name.to_owned(),
+                })
+        })
+        .transpose()
+}
+
+fn cursor_argument<T>(
+    select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    name: &str,
+    decode: impl Fn(&str) -> Option<T>,
+) -> Result<Option<T>> {
+    select
+        .argument(name)
+        .map(|v| {
+            String::from_look_ahead(v.value())
+                .and_then(|c| decode(&c))
+                .ok_or_else(|| WundergraphError::InvalidCursor {
+                    name: name.to_owned(),
+                })
+        })
+        .transpose()
+}
+
+/// Register the `first`, `after`, `last` and `before` arguments
+/// of a connection field
+pub fn register_connection_arguments<'r>(
+    registry: &mut Registry<'r, WundergraphScalarValue>,
+    field: meta::Field<'r, WundergraphScalarValue>,
+) -> meta::Field<'r, WundergraphScalarValue> {
+    field
+        .argument(registry.arg::<Option<i32>>("first", &()))
+        .argument(registry.arg::<Option<String>>("after", &()))
+        .argument(registry.arg::<Option<i32>>("last", &()))
+        .argument(registry.arg::<Option<String>>("before", &()))
+}
+
+/// The pagination arguments of a connection field
+///
+/// `C` is the type of the decoded `after` and `before` cursors
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PageArguments<C> {
+    first: Option<i64>,
+    after: Option<C>,
+    last: Option<i64>,
+    before: Option<C>,
+}
+
+/// The pagination arguments of a connection field using offset cursors
+pub type ConnectionArguments = PageArguments<i64>;
+
+/// The pagination arguments of a keyset paginated connection field
+pub type KeysetArguments = PageArguments<Vec<Option<WundergraphScalarValue>>>;
+
+impl<C> PageArguments<C> {
+    /// Apply the default and maximal limit of the given policy
+    ///
+    /// A connection with only a `before` cursor returns the last page
+    /// before this cursor
+    pub fn limited(self, policy: &LimitPolicy) -> Result<Self> {
+        let first = self.first.map(|f| policy.check("first", f)).transpose()?;
+        let last = self.last.map(|l| policy.check("last", l)).transpose()?;
+        if first.is_some() || last.is_some() {
+            return Ok(Self {
+                first,
+                last,
+                ..self
+            });
+        }
+        let default = policy.limit("first", None)?;
+        if self.before.is_some() && self.after.is_none() {
+            Ok(Self {
+                last: default,
+                ..self
+            })
+        } else {
+            Ok(Self {
+                first: default,
+                ..self
+            })
+        }
+    }
+}
+
+/// The range of positions of the entities returned by a connection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConnectionWindow {
+    /// Position of the first entity in the window
+    pub start: i64,
+    /// Position after the last entity in the window, `None` if the
+    /// window extends to the end of the result
+    pub end: Option<i64>,
+}
+
+impl ConnectionArguments {
+    /// Read the pagination arguments from the given connection field
+    pub fn from_select(select: &LookAheadSelection<'_, WundergraphScalarValue>) -> Result<Self> {
+        Ok(Self {
+            first: count_argument(select, "first")?,
+            after: cursor_argument(select, "after", decode_cursor)?,
+            last: count_argument(select, "last")?,
+            before: cursor_argument(select, "before", decode_cursor)?,
+        })
+    }
+
+    /// Compute the window of entities to return
+    ///
+    /// `total` is only called if the end of the result is required
+    /// to compute the window, that is if `last` is given without `before`
+    pub fn window(&self, total: impl FnOnce() -> Result<i64>) -> Result<ConnectionWindow> {
+        // Only cursors close to `i64::MAX` could overflow
+        let overflow = || WundergraphError::InvalidCursor {
+            name: String::from("after"),
+        };
+        let start = match self.after {
+            Some(after) => after.checked_add(1).ok_or_else(overflow)?,
+            None => 0,
+        };
+        let mut end = self.before.map(|b| b.max(start));
+        if let Some(first) = self.first {
+            let first_end = start.checked_add(first).ok_or_else(overflow)?;
+            end = Some(end.map_or(first_end, |e| e.min(first_end)));
+        }
+        if let Some(last) = self.last {
+            let e = match end {
+                Some(e) => e,
+                None => total()?.max(start),
+            };
+            return Ok(ConnectionWindow {
+                start: start.max(e - last),
+                end: Some(e),
+            });
+        }
+        Ok(ConnectionWindow { start, end })
+    }
+}
+
+impl ConnectionWindow {
+    /// Number of entities to load for this window
+    ///
+    /// This includes one additional entity to check if there is a next page
+    pub fn limit(&self) -> Option<i64> {
+        self.end.map(|e| (e - self.start).saturating_add(1))
+    }
+
+    /// Apply limit and offset clauses for this window to the given query
+    pub fn apply<'a, L, DB, Ctx>(
+        &self,
+        mut query: super::BoxedQuery<'a, L, DB, Ctx>,
+    ) -> super::BoxedQuery<'a, L, DB, Ctx>
+    where
+        DB: Backend + ApplyOffset + 'static,
+        L: LoadingHandler<DB, Ctx>,
+        L::Table: 'static,
+        <L::Table as QuerySource>::FromClause: QueryFragment<DB>,
+        DB::QueryBuilder: Default,
+    {
+        if let Some(limit) = self.limit() {
+            query = <_ as diesel::query_dsl::methods::LimitDsl>::limit(query, limit);
+        }
+        if self.start > 0 {
+            query = DB::apply_offset_value::<L, Ctx>(query, self.start, self.limit().is_some());
+        }
+        query
+    }
+
+    /// Build the response of a connection field out of the loaded entities
+    ///
+    /// `nodes` may contain one additional entity indicating that there is
+    /// a next page
+    pub fn build_response(
+        &self,
+        type_name: &str,
+        mut nodes: Vec<Value<WundergraphScalarValue>>,
+        total_count: Option<i64>,
+        selection: Option<&[Selection<'_, WundergraphScalarValue>]>,
+    ) -> Value<WundergraphScalarValue> {
+        let has_next_page = match self.end {
+            Some(e) if nodes.len() as i64 > e - self.start => {
+                nodes.truncate((e - self.start) as usize);
+                true
+            }
+            _ => false,
+        };
+        let cursors = (self.start..)
+            .take(nodes.len())
+            .map(encode_cursor)
+            .collect::<Vec<_>>();
+        build_connection(
+            type_name,
+            &nodes,
+            &cursors,
+            self.start > 0,
+            has_next_page,
+            total_count,
+            selection,
+        )
+    }
+}
+
+/// A column used to order a keyset paginated connection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeysetColumn {
+    /// The local index of the corresponding table field
+    pub index: usize,
+    /// The graphql name of the corresponding field
+    pub name: &'static str,
+    /// The order direction of this column
+    pub order: Order,
+    /// Whether the corresponding field may be `NULL`
+    pub nullable: bool,
+}
+
+/// Collect the columns used to order a keyset paginated connection
+///
+/// Those are the columns given by the `order` argument, or by
+/// `LoadingHandler::DEFAULT_ORDER` if there is no such argument, followed
+/// by the primary key columns. Only fields backed by a column of the
+/// current table are supported, setting `nulls` is not supported as
+/// `NULL` values are always sorted as largest value. Whether a column
+/// is nullable is taken from the type of the field in the schema.
+pub fn keyset_columns<L, DB, Ctx>(
+    select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+) -> Result<Vec<KeysetColumn>>
+where
+    DB: Backend + ApplyOffset + 'static,
+    L: LoadingHandler<DB, Ctx>,
+{
+    let entity = executor.schema().concrete_type_by_name(L::TYPE_NAME);
+    let field_index = |name: &str| {
+        (0..L::FieldList::TABLE_FIELD_COUNT).find(|i| {
+            L::FieldList::map_table_field(*i, |global| L::FIELD_NAMES[global] == name)
+                .unwrap_or(false)
+        })
+    };
+    let column = |index: usize, order| {
+        let name = L::FieldList::map_table_field(index, |global| L::FIELD_NAMES[global])
+            .expect("Field is there");
+        let nullable = entity
+            .and_then(|e| e.field_by_name(name))
+            .map_or(true, |f| !f.field_type.is_non_null());
+        KeysetColumn {
+            index,
+            name,
+            order,
+            nullable,
+        }
+    };
+    let mut columns = Vec::new();
+    match select.argument("order").map(LookAheadArgument::value) {
+        Some(LookAheadValue::List(order)) => {
+            for (idx, o) in order.iter().enumerate() {
+                let o = if let LookAheadValue::Object(o) = o {
+                    o
+                } else {
+                    return Err(WundergraphError::InvalidOrderArgument {
+                        path: format!("order[{}]", idx),
+                    });
+                };
+                let index = o
+                    .iter()
+                    .find(|(k, _)| *k == "column")
+                    .and_then(|(_, v)| {
+                        if let LookAheadValue::Enum(c) = v {
+                            field_index(c)
+                        } else {
+                            None
+                        }
+                    })
+                    .ok_or_else(|| WundergraphError::InvalidOrderArgument {
+                        path: format!("order[{}].column", idx),
+                    })?;
+                let order = match o.iter().find(|(k, _)| *k == "direction") {
+                    None | Some((_, LookAheadValue::Null)) => Order::Asc,
+                    Some((_, v)) => Order::from_look_ahead(v).ok_or_else(|| {
+                        WundergraphError::InvalidOrderArgument {
+                            path: format!("order[{}].direction", idx),
+                        }
+                    })?,
+                };
+                match o.iter().find(|(k, _)| *k == "nulls") {
+                    None | Some((_, LookAheadValue::Null)) => {}
+                    Some(_) => {
+                        return Err(WundergraphError::InvalidOrderArgument {
+                            path: format!("order[{}].nulls", idx),
+                        })
+                    }
+                }
+                columns.push(column(index, order));
+            }
+        }
+        Some(_) => {
+            return Err(WundergraphError::InvalidOrderArgument {
+                path: String::from("order"),
+            })
+        }
+        None => {
+            for (name, order) in L::DEFAULT_ORDER {
+                let index =
+                    field_index(name).ok_or_else(|| WundergraphError::UnsupportedKeysetColumn {
+                        name: (*name).to_owned(),
+                    })?;
+                columns.push(column(index, *order));
+            }
+        }
+    }
+    for index in 0..L::FieldList::TABLE_FIELD_COUNT {
+        if L::PrimaryKeyIndex::is_index(index) && columns.iter().all(|c| c.index != index) {
+            columns.push(column(index, Order::Asc));
+        }
+    }
+    Ok(columns)
+}
+
+impl KeysetArguments {
+    /// Read the pagination arguments from the given connection field
+    pub fn from_select(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        columns: &[KeysetColumn],
+    ) -> Result<Self> {
+        let decode = |c: &str| decode_keyset_cursor(c, columns);
+        Ok(Self {
+            first: count_argument(select, "first")?,
+            after: cursor_argument(select, "after", decode)?,
+            last: count_argument(select, "last")?,
+            before: cursor_argument(select, "before", decode)?,
+        })
+    }
+
+    // Only `last` requires to load the page from the end
+    fn is_backward(&self) -> bool {
+        self.first.is_none() && self.last.is_some()
+    }
+
+    /// Build the filters restricting the result to entities after the
+    /// `after` cursor and before the `before` cursor
+    ///
+    /// Returns pairs of argument names and filter values
+    pub fn conditions<'a>(
+        &'a self,
+        columns: &[KeysetColumn],
+    ) -> Vec<(&'static str, LookAheadValue<'a, WundergraphScalarValue>)> {
+        let mut ret = Vec::with_capacity(2);
+        if let Some(ref after) = self.after {
+            ret.push(("after", keyset_condition(columns, after, true)));
+        }
+        if let Some(ref before) = self.before {
+            ret.push(("before", keyset_condition(columns, before, false)));
+        }
+        ret
+    }
+
+    /// The order used to load the page, reversed if the page is loaded
+    /// from the end of the result
+    pub fn order(
+        &self,
+        columns: &[KeysetColumn],
+    ) -> Vec<LookAheadValue<'static, WundergraphScalarValue>> {
+        columns
+            .iter()
+            .map(|c| {
+                // `NULL` is sorted as largest value on all backends,
+                // `keyset_condition` relies on this
+                let (direction, nulls) = match (c.order, self.is_backward()) {
+                    (Order::Asc, false) | (Order::Desc, true) => ("ASC", "LAST"),
+                    (Order::Desc, false) | (Order::Asc, true) => ("DESC", "FIRST"),
+                };
+                LookAheadValue::Object(vec![
+                    ("column", LookAheadValue::Enum(c.name)),
+                    ("direction", LookAheadValue::Enum(direction)),
+                    ("nulls", LookAheadValue::Enum(nulls)),
+                ])
+            })
+            .collect()
+    }
+
+    /// Number of entities to load
+    ///
+    /// This includes one additional entity to check if there is a next
+    /// (or previous) page
+    pub fn limit(&self) -> Option<i64> {
+        if self.is_backward() {
+            self.last.map(|l| l + 1)
+        } else {
+            self.first.map(|f| f + 1)
+        }
+    }
+
+    /// Restrict the loaded entities to the requested page
+    ///
+    /// Returns whether there is a previous and a next page
+    pub fn page<T>(&self, rows: &mut Vec<T>) -> (bool, bool) {
+        if self.is_backward() {
+            let last = self.last.unwrap_or_default() as usize;
+            let has_previous_page = rows.len() > last;
+            rows.truncate(last);
+            rows.reverse();
+            return (has_previous_page, false);
+        }
+        let mut has_next_page = false;
+        if let Some(first) = self.first {
+            has_next_page = rows.len() > first as usize;
+            rows.truncate(first as usize);
+        }
+        let mut has_previous_page = false;
+        if let Some(last) = self.last {
+            let skip = rows.len().saturating_sub(last as usize);
+            has_previous_page = skip > 0;
+            rows.drain(..skip);
+        }
+        (has_previous_page, has_next_page)
+    }
+}
+
+// Expands to `c1 >= v1 AND (c1 > v1 OR (c1 = v1 AND c2 > v2) OR ...)`,
+// the first comparison allows the database to use an index on `c1`.
+// `NULL` is handled as largest value, so `c > v` becomes
+// `c > v OR c IS NULL`, `c < NULL` becomes `c IS NOT NULL` and
+// `c > NULL` drops the whole alternative. As the primary key is
+// never `NULL` at least one alternative remains. Columns that are not
+// nullable have no `is_null` filter, for them `c > v` is kept as is.
+fn keyset_condition<'a>(
+    columns: &[KeysetColumn],
+    values: &'a [Option<WundergraphScalarValue>],
+    after: bool,
+) -> LookAheadValue<'a, WundergraphScalarValue> {
+    let filter = |idx: usize, op: &'static str, value| {
+        LookAheadValue::Object(vec![(
+            columns[idx].name,
+            LookAheadValue::Object(vec![(op, value)]),
+        )])
+    };
+    let is_null = |idx: usize, is_null: bool| {
+        filter(
+            idx,
+            "is_null",
+            LookAheadValue::Scalar(&IS_NULL[is_null as usize]),
+        )
+    };
+    let eq = |idx: usize| match values[idx] {
+        Some(ref v) => filter(idx, "eq", LookAheadValue::Scalar(v)),
+        None => is_null(idx, true),
+    };
+    // Returns `None` if the comparison does not restrict the result,
+    // or if no row matches a strict comparison
+    let compare = |idx: usize, strict: bool| {
+        let greater = (columns[idx].order == Order::Asc) == after;
+        match (&values[idx], greater) {
+            (Some(v), true) if columns[idx].nullable => {
+                let op = if strict { "gt" } else { "gte" };
+                Some(LookAheadValue::Object(vec![(
+                    "or",
+                    LookAheadValue::List(vec![
+                        filter(idx, op, LookAheadValue::Scalar(v)),
+                        is_null(idx, true),
+                    ]),
+                )]))
+            }
+            (Some(v), true) => {
+                let op = if strict { "gt" } else { "gte" };
+                Some(filter(idx, op, LookAheadValue::Scalar(v)))
+            }
+            (Some(v), false) => {
+                let op = if strict { "lt" } else { "lte" };
+                Some(filter(idx, op, LookAheadValue::Scalar(v)))
+            }
+            (None, true) if strict => None,
+            (None, true) => Some(is_null(idx, true)),
+            (None, false) if strict => Some(is_null(idx, false)),
+            (None, false) => None,
+        }
+    };
+    let alternatives = (0..columns.len())
+        .filter_map(|idx| {
+            let mut and = (0..idx).map(eq).collect::<Vec<_>>();
+            and.push(compare(idx, true)?);
+            Some(LookAheadValue::Object(vec![(
+                "and",
+                LookAheadValue::List(and),
+            )]))
+        })
+        .collect();
+    let mut and = Vec::with_capacity(2);
+    and.extend(compare(0, false));
+    and.push(LookAheadValue::Object(vec![(
+        "or",
+        LookAheadValue::List(alternatives),
+    )]));
+    LookAheadValue::Object(vec![("and", LookAheadValue::List(and))])
+}
+
+/// Build the response of a connection field
+///
+/// `cursors` contains the cursor of each entity in `nodes`, `total_count`
+/// is only required if `totalCount` is part of the selection
+pub fn build_connection(
+    type_name: &str,
+    nodes: &[Value<WundergraphScalarValue>],
+    cursors: &[String],
+    has_previous_page: bool,
+    has_next_page: bool,
+    total_count: Option<i64>,
+    selection: Option<&[Selection<'_, WundergraphScalarValue>]>,
+) -> Value<WundergraphScalarValue> {
+    build_object(selection, |name, selection| match name {
+        "__typename" => Some(Value::scalar(format!("{}Connection", type_name))),
+        "totalCount" => total_count.map(Value::scalar),
+        "edges" => Some(Value::list(
+            nodes
+                .iter()
+                .zip(cursors)
+                .map(|(node, cursor)| {
+                    build_object(selection, |name, _| match name {
+                        "__typename" => Some(Value::scalar(format!("{}Edge", type_name))),
+                        "cursor" => Some(Value::scalar(cursor.clone())),
+                        "node" => Some(node.clone()),
+                        _ => None,
+                    })
+                })
+                .collect(),
+        )),
+        "pageInfo" => Some(build_object(selection, |name, _| match name {
+            "__typename" => Some(Value::scalar("PageInfo")),
+            "hasNextPage" => Some(Value::scalar(has_next_page)),
+            "hasPreviousPage" => Some(Value::scalar(has_previous_page)),
+            "startCursor" => Some(
+                cursors
+                    .first()
+                    .map_or_else(Value::null, |c| Value::scalar(c.clone())),
+            ),
+            "endCursor" => Some(
+                cursors
+                    .last()
+                    .map_or_else(Value::null, |c| Value::scalar(c.clone())),
+            ),
+            _ => None,
+        })),
+        _ => None,
+    })
+}
+
+/// Returns the look ahead selection of the `edges { node }` field
+/// of a connection
+pub fn node_look_ahead<'a>(
+    select: &'a LookAheadSelection<'a, WundergraphScalarValue>,
+) -> Option<&'a LookAheadSelection<'a, WundergraphScalarValue>> {
+    select
+        .select_child("edges")
+        .and_then(|e| e.select_child("node"))
+}
+
+fn build_object<'a>(
+    selection: Option<&'a [Selection<'a, WundergraphScalarValue>]>,
+    field: impl Fn(
+        &str,
+        Option<&'a [Selection<'a, WundergraphScalarValue>]>,
+    ) -> Option<Value<WundergraphScalarValue>>,
+) -> Value<WundergraphScalarValue> {
+    let selection = selection.unwrap_or(&[]);
+    let mut obj = Object::with_capacity(selection.len());
+    for s in selection {
+        if let Selection::Field(Spanning { item: ref f, .. }) = *s {
+            let selection = f.selection_set.as_ref().map(|s| s as _);
+            if let Some(value) = field(f.name.item, selection) {
+                obj.add_field(f.alias.unwrap_or(f.name).item, value);
+            }
+        }
+    }
+    Value::Object(obj)
+}
+
+/// A relay connection of wundergraph entities
+#[derive(Debug)]
+pub struct Connection<T, DB, Ctx>(PhantomData<(T, DB, Ctx)>);
+
+/// An edge of a relay connection
+#[derive(Debug)]
+pub struct Edge<T, DB, Ctx>(PhantomData<(T, DB, Ctx)>);
+
+/// Information about the current page of a relay connection
+#[derive(Debug, Clone, Copy)]
+pub struct PageInfo;
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct ConnectionTypeInfo<T, DB, Ctx>(String, PhantomData<(T, DB, Ctx)>);
+
+impl<T, DB, Ctx> Default for ConnectionTypeInfo<T, DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+    T::Table: 'static,
+    <T::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    T: LoadingHandler<DB, Ctx>,
+    DB::QueryBuilder: Default,
+{
+    fn default() -> Self {
+        Self(format!("{}Connection", T::TYPE_NAME), PhantomData)
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct EdgeTypeInfo<T, DB, Ctx>(String, PhantomData<(T, DB, Ctx)>);
+
+impl<T, DB, Ctx> Default for EdgeTypeInfo<T, DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+    T::Table: 'static,
+    <T::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    T: LoadingHandler<DB, Ctx>,
+    DB::QueryBuilder: Default,
+{
+    fn default() -> Self {
+        Self(format!("{}Edge", T::TYPE_NAME), PhantomData)
+    }
+}
+
+impl<T, DB, Ctx> GraphQLType<WundergraphScalarValue> for Connection<T, DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+    T::Table: 'static,
+    <T::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    T: LoadingHandler<DB, Ctx>,
+    T::FieldList: WundergraphGraphqlHelper<T, DB, Ctx>,
+    DB::QueryBuilder: Default,
+{
+    type Context = ();
+    type TypeInfo = ConnectionTypeInfo<T, DB, Ctx>;
+
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        Some(&info.0)
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> meta::MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let fields = &[
+            registry.field::<Vec<Edge<T, DB, Ctx>>>("edges", &Default::default()),
+            registry.field::<PageInfo>("pageInfo", &()),
+            registry
+                .field::<i64>("totalCount", &())
+                .description("Number of entities matching the filter, ignoring pagination"),
+        ];
+        registry.build_object_type::<Self>(info, fields).into_meta()
+    }
+}
+
+impl<T, DB, Ctx> GraphQLType<WundergraphScalarValue> for Edge<T, DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+    T::Table: 'static,
+    <T::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    T: LoadingHandler<DB, Ctx>,
+    T::FieldList: WundergraphGraphqlHelper<T, DB, Ctx>,
+    DB::QueryBuilder: Default,
+{
+    type Context = ();
+    type TypeInfo = EdgeTypeInfo<T, DB, Ctx>;
+
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        Some(&info.0)
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> meta::MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let fields = &[
+            registry.field::<String>("cursor", &()),
+            registry.field::<GraphqlWrapper<T, DB, Ctx>>("node", &()),
+        ];
+        registry.build_object_type::<Self>(info, fields).into_meta()
+    }
+}
+
+impl GraphQLType<WundergraphScalarValue> for PageInfo {
+    type Context = ();
+    type TypeInfo = ();
+
+    fn name(_info: &Self::TypeInfo) -> Option<&str> {
+        Some("PageInfo")
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> meta::MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let fields = &[
+            registry.field::<bool>("hasNextPage", &()),
+            registry.field::<bool>("hasPreviousPage", &()),
+            registry.field::<Option<String>>("startCursor", &()),
+            registry.field::<Option<String>>("endCursor", &()),
+        ];
+        registry.build_object_type::<Self>(info, fields).into_meta()
+    }
+}
@@ -1,16 +1,29 @@
 use super::WundergraphFieldList;
-use crate::error::Result;
+use crate::context::WundergraphContext;
+use crate::error::{Result, WundergraphError};
+use crate::juniper_ext::FromLookAheadValue;
+use crate::query_builder::selection::aggregate::{AggregateSelect, GroupBy};
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::offset::ApplyOffset;
 use crate::query_builder::selection::query_resolver::get_sub_field;
-use crate::query_builder::selection::LoadingHandler;
+use crate::query_builder::selection::window::{ColumnNames, PartitionWindow};
+use crate::query_builder::selection::{BoxedQuery, LoadingHandler, QueryModifier};
 use crate::query_builder::types::HasMany;
 use crate::scalar::WundergraphScalarValue;
 use diesel::associations::HasTable;
 use diesel::backend::Backend;
-use diesel::expression::NonAggregate;
-use diesel::query_builder::QueryFragment;
-use diesel::{QuerySource, SelectableExpression};
+use diesel::dsl::SqlTypeOf;
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate};
+use diesel::query_builder::{BoxedSelectStatement, QueryFragment};
+use diesel::query_dsl::methods::{BoxedDsl, FilterDsl, SelectDsl};
+use diesel::serialize::ToSql;
+use diesel::sql_types::{BigInt, Bool, HasSqlType, IntoNullable};
+use diesel::{
+    Connection, NullableExpressionMethods, QueryDsl, QuerySource, Queryable, RunQueryDsl,
+    SelectableExpression, Table,
+};
 use juniper::{Executor, LookAheadMethods, Selection};
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::hash::Hash;
 
@@ -18,8 +31,9 @@ use std::hash::Hash;
 #[derive(Debug)]
 pub struct AssociationsReturn<'a, K: Eq + Hash> {
     keys: Vec<Option<K>>,
-    fields: Vec<&'a str>,
-    values: HashMap<Option<K>, Vec<(usize, Vec<juniper::Value<WundergraphScalarValue>>)>>,
+    // Output name and value used for keys without any value
+    fields: Vec<(Cow<'a, str>, juniper::Value<WundergraphScalarValue>)>,
+    values: HashMap<Option<K>, Vec<(usize, juniper::Value<WundergraphScalarValue>)>>,
 }
 
 impl<'a, K: Eq + Hash> AssociationsReturn<'a, K> {
@@ -55,10 +69,41 @@ impl<'a, K: Eq + Hash> AssociationsReturn<'a, K> {
         let values = T::resolve(global_args, look_ahead, selection, &self.keys, &executor)?;
 
         let len = self.fields.len();
-        self.fields.push(alias);
+        self.fields
+            .push((Cow::Borrowed(alias), juniper::Value::List(Vec::new())));
 
         for (k, v) in values {
-            self.values.entry(k).or_insert_with(Vec::new).push((len, v));
+            self.values
+                .entry(k)
+                .or_insert_with(Vec::new)
+                .push((len, juniper::Value::List(v)));
+        }
+        Ok(())
+    }
+
+    fn push_count<T, O, DB, Ctx>(
+        &mut self,
+        field: &str,
+        look_ahead: &juniper::LookAheadSelection<'a, WundergraphScalarValue>,
+        selection: Option<&'a [Selection<'a, WundergraphScalarValue>]>,
+        executor: &'a Executor<'a, Ctx, WundergraphScalarValue>,
+    ) -> Result<()>
+    where
+        DB: Backend,
+        T: WundergraphResolveAssociation<K, O, DB, Ctx>,
+    {
+        let (_, alias, _, _) = get_sub_field(field, selection);
+        let counts = T::resolve_count(look_ahead, &self.keys, executor)?;
+
+        let len = self.fields.len();
+        self.fields
+            .push((Cow::Owned(alias.to_owned()), juniper::Value::scalar(0_i64)));
+
+        for (k, count) in counts {
+            self.values
+                .entry(k)
+                .or_insert_with(Vec::new)
+                .push((len, juniper::Value::scalar(count)));
         }
         Ok(())
     }
@@ -81,7 +126,7 @@ impl<'a, K: Eq + Hash> AssociationsReturn<'a, K> {
                     let values = values.get(&key);
                     if let Some(values) = values {
                         let mut value_iter = values.iter().peekable();
-                        for (idx, field_name) in fields.iter().enumerate() {
+                        for (idx, (field_name, default)) in fields.iter().enumerate() {
                             match value_iter.peek() {
                                 Some((field_idx, _)) if idx == *field_idx => {
                                     let value = value_iter
@@ -89,22 +134,16 @@ impl<'a, K: Eq + Hash> AssociationsReturn<'a, K> {
                                         .expect("It's there because peekable")
                                         .1
                                         .clone();
-                                    obj.add_field(
-                                        field_name.to_owned(),
-                                        juniper::Value::List(value),
-                                    );
+                                    obj.add_field(&**field_name, value);
                                 }
                                 None | Some(_) => {
-                                    obj.add_field(
-                                        field_name.to_owned(),
-                                        juniper::Value::List(Vec::new()),
-                                    );
+                                    obj.add_field(&**field_name, default.clone());
                                 }
                             }
                         }
                     } else {
-                        for f in &fields {
-                            obj.add_field(f.to_owned(), juniper::Value::List(Vec::new()));
+                        for (field_name, default) in &fields {
+                            obj.add_field(&**field_name, default.clone());
                         }
                     }
                     obj
@@ -157,6 +196,12 @@ pub trait WundergraphResolveAssociation<K, Other, DB: Backend, Ctx> {
         primary_keys: &[Option<K>],
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
     ) -> Result<HashMap<Option<K>, Vec<juniper::Value<WundergraphScalarValue>>>>;
+
+    fn resolve_count(
+        look_ahead: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
+        primary_keys: &[Option<K>],
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<HashMap<Option<K>, i64>>;
 }
 
 /// A helper trait used to resolve a association given by a `HasOne` marker type
@@ -168,7 +213,7 @@ pub trait WundergraphResolveAssociation<K, Other, DB: Backend, Ctx> {
 /// # Type parameters:
 /// * `Self`: Type implementing `LoadingHandler`
 /// * `Other`: Table of type referenced by field
-/// * `DB`: Backend type from diesel, so one of `Pg` or `Sqlite`
+/// * `DB`: Backend type from diesel, so one of `Pg`, `Sqlite` or `Mysql`
 /// * `Ctx`: The used wundergraph context type
 /// * `FK`: Foreign key referencing the remote table
 ///
@@ -187,7 +232,7 @@ pub trait WundergraphResolveAssociation<K, Other, DB: Backend, Ctx> {
 /// ```
 /// # #[macro_use] extern crate diesel;
 /// # use wundergraph::helper::TupleIndex0;
-/// # use wundergraph::query_builder::selection::LoadingHandler;
+/// # use wundergraph::query_builder::selection::{LoadingHandler, QueryModifier};
 /// # use wundergraph::WundergraphEntity;
 /// #
 /// use wundergraph::query_builder::types::HasOne;
@@ -249,7 +294,7 @@ pub trait WundergraphResolveAssociation<K, Other, DB: Backend, Ctx> {
 /// # #[cfg(feature = "postgres")]
 /// impl<Ctx> WundergraphBelongsTo<species::table, Pg, Ctx, heros::species> for Hero
 /// where
-///     Ctx: WundergraphContext + 'static,
+///     Ctx: WundergraphContext + QueryModifier<Hero, Pg> + 'static,
 ///     <Ctx as WundergraphContext>::Connection: Connection<Backend = Pg>,
 /// {
 ///    type Key = i32;
@@ -263,12 +308,15 @@ pub trait WundergraphResolveAssociation<K, Other, DB: Backend, Ctx> {
 ///    ) -> Result<HashMap<Option<Self::Key>, Vec<juniper::Value<WundergraphScalarValue>>>>
 ///    {
 ///        let conn = executor.context().get_connection();
-///        let query = <Self as LoadingHandler<Pg, Ctx>>::build_query(global_args, look_ahead)?
+///        let query = <Self as WundergraphBelongsTo<species::table, Pg, Ctx, heros::species>>::build_association_query(
+///                look_ahead,
+///                heros::species.nullable().eq_any(keys.to_vec()),
+///                executor,
+///            )?
 ///            .select((
 ///                heros::species.nullable(),
 ///                <Self as LoadingHandler<Pg, Ctx>>::get_select(look_ahead)?
-///             ))
-///            .filter(heros::species.nullable().eq_any(keys));
+///             ));
 ///        <Self as WundergraphBelongsTo<species::table, Pg, Ctx, heros::species>>::build_response(
 ///            query.load(conn)?,
 ///            global_args,
@@ -303,6 +351,239 @@ where
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
     ) -> Result<HashMap<Option<Self::Key>, Vec<juniper::Value<WundergraphScalarValue>>>>;
 
+    /// Count the entities referencing each of the given keys
+    ///
+    /// Called to resolve the `{field}Count` field generated for each
+    /// `HasMany` field. Keys without any entity could be omitted. The
+    /// default implementation loads all entities via `resolve`,
+    /// [`#[derive(WundergraphEntity)]`](../derive.WundergraphEntity.html)
+    /// generates an implementation calling `count_associations`
+    fn resolve_count(
+        look_ahead: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
+        keys: &[Option<Self::Key>],
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<HashMap<Option<Self::Key>, i64>> {
+        Ok(Self::resolve(&[], look_ahead, None, keys, executor)?
+            .into_iter()
+            .map(|(key, values)| (key, values.len() as i64))
+            .collect())
+    }
+
+    /// Count the entities matching the `key_filter` grouped by the foreign key
+    ///
+    /// Compiles down to
+    /// `SELECT fk, COUNT(*) FROM table WHERE key_filter AND filter GROUP BY fk`,
+    /// so all counts are loaded by a single query. Only entities returned by
+    /// the `QueryModifier` of the context are counted
+    fn count_associations<'a, F>(
+        look_ahead: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
+        key_filter: F,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<HashMap<Option<Self::Key>, i64>>
+    where
+        F: Expression<SqlType = Bool>
+            + AppearsOnTable<Self::Table>
+            + NonAggregate
+            + QueryFragment<DB>
+            + 'a,
+        Self::Table: BoxedDsl<
+            'a,
+            DB,
+            Output = BoxedSelectStatement<
+                'a,
+                SqlTypeOf<<Self::Table as Table>::AllColumns>,
+                Self::Table,
+                DB,
+            >,
+        >,
+        <Self::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<Self::Table>,
+        FK: 'a,
+        FK::SqlType: IntoNullable,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        DB: HasSqlType<(<FK::SqlType as IntoNullable>::Nullable, BigInt)>,
+        (Option<Self::Key>, i64): Queryable<(<FK::SqlType as IntoNullable>::Nullable, BigInt), DB>,
+    {
+        Self::count_associations_with(look_ahead, key_filter, executor, |query| {
+            executor.context().modify_query(look_ahead, query)
+        })
+    }
+
+    /// Same as `count_associations`, but passes the query to `modify`
+    /// instead of the `QueryModifier` of the context
+    #[doc(hidden)]
+    fn count_associations_with<'a, F, M>(
+        look_ahead: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
+        key_filter: F,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        modify: M,
+    ) -> Result<HashMap<Option<Self::Key>, i64>>
+    where
+        F: Expression<SqlType = Bool>
+            + AppearsOnTable<Self::Table>
+            + NonAggregate
+            + QueryFragment<DB>
+            + 'a,
+        M: FnOnce(BoxedQuery<'a, Self, DB, Ctx>) -> Result<BoxedQuery<'a, Self, DB, Ctx>>,
+        Self::Table: BoxedDsl<
+            'a,
+            DB,
+            Output = BoxedSelectStatement<
+                'a,
+                SqlTypeOf<<Self::Table as Table>::AllColumns>,
+                Self::Table,
+                DB,
+            >,
+        >,
+        <Self::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<Self::Table>,
+        FK: 'a,
+        FK::SqlType: IntoNullable,
+        Ctx: WundergraphContext,
+        Ctx::Connection: Connection<Backend = DB>,
+        DB: HasSqlType<(<FK::SqlType as IntoNullable>::Nullable, BigInt)>,
+        (Option<Self::Key>, i64): Queryable<(<FK::SqlType as IntoNullable>::Nullable, BigInt), DB>,
+    {
+        let query =
+            <_ as SelectDsl<_>>::select(Self::table().into_boxed(), Self::get_select(look_ahead)?);
+        let query = Self::apply_filter(query, look_ahead)?;
+        let query = modify(<_ as FilterDsl<_>>::filter(query, key_filter))?;
+        // `COUNT(*)` is an aggregate, so it could not be part of a tuple
+        let query = GroupBy::new(
+            <_ as SelectDsl<_>>::select(
+                query,
+                AggregateSelect::new(FK::default().nullable(), diesel::dsl::count_star()),
+            ),
+            vec![0],
+        );
+        #[cfg(feature = "debug")]
+        {
+            log::debug!("{:?}", diesel::debug_query(&query));
+        }
+        let counts: Vec<(Option<Self::Key>, i64)> =
+            RunQueryDsl::load(query, executor.context().get_connection())?;
+        Ok(counts.into_iter().collect())
+    }
+
+    /// Build the query loading all entities matching the `key_filter`
+    ///
+    /// Applies the `filter` and `order` arguments of the association field.
+    /// The `limit` and `offset` arguments are applied to the entities
+    /// referencing each parent separately, see
+    /// [`PartitionWindow`](../window/struct.PartitionWindow.html). Only
+    /// entities returned by the `QueryModifier` of the context are loaded
+    /// and counted for those arguments. `K` is inferred from the primary key
+    /// of the table.
+    fn build_association_query<'a, F, K>(
+        look_ahead: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
+        key_filter: F,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<BoxedQuery<'a, Self, DB, Ctx>>
+    where
+        F: Expression<SqlType = Bool>
+            + AppearsOnTable<Self::Table>
+            + NonAggregate
+            + QueryFragment<DB>
+            + Clone
+            + 'a,
+        Self::Table: BoxedDsl<
+            'a,
+            DB,
+            Output = BoxedSelectStatement<
+                'a,
+                SqlTypeOf<<Self::Table as Table>::AllColumns>,
+                Self::Table,
+                DB,
+            >,
+        >,
+        <Self::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<Self::Table>,
+        Self::Table: Clone,
+        <Self::Table as Table>::PrimaryKey: QueryFragment<DB> + ColumnNames<K>,
+        FK: 'a,
+        Ctx: QueryModifier<Self, DB>,
+        DB: HasSqlType<BigInt>,
+        i64: ToSql<BigInt, DB>,
+    {
+        Self::build_association_query_with(look_ahead, key_filter, |query| {
+            executor.context().modify_query(look_ahead, query)
+        })
+    }
+
+    /// Same as `build_association_query`, but passes the queries to `modify`
+    /// instead of the `QueryModifier` of the context
+    #[doc(hidden)]
+    fn build_association_query_with<'a, F, K, M>(
+        look_ahead: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
+        key_filter: F,
+        modify: M,
+    ) -> Result<BoxedQuery<'a, Self, DB, Ctx>>
+    where
+        F: Expression<SqlType = Bool>
+            + AppearsOnTable<Self::Table>
+            + NonAggregate
+            + QueryFragment<DB>
+            + Clone
+            + 'a,
+        M: Fn(BoxedQuery<'a, Self, DB, Ctx>) -> Result<BoxedQuery<'a, Self, DB, Ctx>>,
+        Self::Table: BoxedDsl<
+            'a,
+            DB,
+            Output = BoxedSelectStatement<
+                'a,
+                SqlTypeOf<<Self::Table as Table>::AllColumns>,
+                Self::Table,
+                DB,
+            >,
+        >,
+        <Self::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<Self::Table>,
+        Self::Table: Clone,
+        <Self::Table as Table>::PrimaryKey: QueryFragment<DB> + ColumnNames<K>,
+        FK: 'a,
+        DB: HasSqlType<BigInt>,
+        i64: ToSql<BigInt, DB>,
+    {
+        // Rows hidden by the context must neither be loaded nor occupy
+        // a position of the window
+        let filtered = || -> Result<BoxedQuery<'a, Self, DB, Ctx>> {
+            let query = <_ as SelectDsl<_>>::select(
+                Self::table().into_boxed(),
+                Self::get_select(look_ahead)?,
+            );
+            let query = Self::apply_filter(query, look_ahead)?;
+            modify(<_ as FilterDsl<_>>::filter(query, key_filter.clone()))
+        };
+        let query = Self::apply_order(filtered()?, look_ahead)?;
+
+        let limit = look_ahead
+            .argument("limit")
+            .map(|l| {
+                i64::from_look_ahead(l.value())
+                    .filter(|l| *l >= 0)
+                    .ok_or(WundergraphError::InvalidLimitArgument)
+            })
+            .transpose()?;
+        let offset = look_ahead
+            .argument("offset")
+            .map(|o| {
+                i64::from_look_ahead(o.value())
+                    .filter(|o| *o >= 0)
+                    .ok_or(WundergraphError::InvalidOffsetArgument)
+            })
+            .transpose()?;
+        if limit.is_none() && offset.is_none() {
+            return Ok(query);
+        }
+
+        let window = PartitionWindow::new(
+            Self::table(),
+            filtered()?,
+            FK::default(),
+            Self::build_order_clauses(look_ahead)?,
+            offset.unwrap_or(0),
+            limit,
+        );
+        Ok(<_ as FilterDsl<_>>::filter(query, window))
+    }
+
     /// Common part of the implementation that could be implemented in a
     /// generic way. Call this as soon as you have all required data
     fn build_response(
@@ -363,6 +644,14 @@ where
     ) -> Result<HashMap<Option<K>, Vec<juniper::Value<WundergraphScalarValue>>>> {
         T::resolve(global_args, look_ahead, selection, primary_keys, executor)
     }
+
+    fn resolve_count(
+        look_ahead: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
+        primary_keys: &[Option<K>],
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<HashMap<Option<K>, i64>> {
+        T::resolve_count(look_ahead, primary_keys, executor)
+    }
 }
 
 macro_rules! wundergraph_impl_resolve_association {
@@ -399,6 +688,16 @@ macro_rules! wundergraph_impl_resolve_association {
                                 executor
                             )?;
                         }
+                        let count = format!("{}Count", get_name($idx));
+                        if let Some(look_ahead) = look_ahead.select_child(&count) {
+                            ret.init(&get_keys);
+                            ret.push_count::<$T, Other, Back, Ctx>(
+                                &count,
+                                look_ahead,
+                                selection,
+                                executor
+                            )?;
+                        }
                     )*
                     Ok(ret)
                 }
@@ -40,6 +40,26 @@ pub trait WundergraphFieldList<DB: Backend, Key, Table, Ctx> {
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
     ) -> Result<Vec<juniper::Value<WundergraphScalarValue>>>;
 
+    /// Resolve the field list like `resolve`, additionally returning the
+    /// values of the table fields at the given local indices for each
+    /// entity contained in `placeholder`
+    ///
+    /// Fields that could not be resolved to a single value are returned
+    /// as `None`
+    #[allow(clippy::type_complexity)]
+    fn resolve_with_columns(
+        placeholder: Vec<Self::PlaceHolder>,
+        columns: &[usize],
+        global_args: &[juniper::LookAheadArgument<WundergraphScalarValue>],
+        select: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        name_list: &'static [&'static str],
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<(
+        Vec<juniper::Value<WundergraphScalarValue>>,
+        Vec<Vec<Option<juniper::Value<WundergraphScalarValue>>>>,
+    )>;
+
     #[doc(hidden)]
     fn map_table_field<F: Fn(usize) -> R, R>(local_index: usize, callback: F) -> Option<R>;
     #[doc(hidden)]
@@ -85,6 +105,29 @@ macro_rules! wundergraph_impl_field_list {
                     name_list: &'static [&'static str],
                     executor: &Executor<'_, Ctx, WundergraphScalarValue>,
                 ) -> Result<Vec<juniper::Value<WundergraphScalarValue>>> {
+                    Self::resolve_with_columns(
+                        placeholder,
+                        &[],
+                        global_args,
+                        look_ahead,
+                        selection,
+                        name_list,
+                        executor,
+                    ).map(|(values, _)| values)
+                }
+
+                fn resolve_with_columns(
+                    placeholder: Vec<Self::PlaceHolder>,
+                    columns: &[usize],
+                    global_args: &[juniper::LookAheadArgument<WundergraphScalarValue>],
+                    look_ahead: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
+                    selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+                    name_list: &'static [&'static str],
+                    executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+                ) -> Result<(
+                    Vec<juniper::Value<WundergraphScalarValue>>,
+                    Vec<Vec<Option<juniper::Value<WundergraphScalarValue>>>>,
+                )> {
                     let extern_values = {
                         let keys = || {
                             placeholder.iter()
@@ -108,7 +151,8 @@ macro_rules! wundergraph_impl_field_list {
                             name_list[pos]
                         }).expect("Name is there")
                     };
-                    let objs = placeholder.resolve(
+                    let (objs, column_values) = placeholder.resolve_with_columns(
+                        columns,
                         name,
                         global_args,
                         look_ahead,
@@ -116,7 +160,7 @@ macro_rules! wundergraph_impl_field_list {
                         executor,
                     )?;
 
-                     Ok(extern_values.merge_with_object_list(objs))
+                     Ok((extern_values.merge_with_object_list(objs), column_values))
                 }
 
                 #[inline(always)]
@@ -0,0 +1,96 @@
+#[cfg(feature = "postgres")]
+use crate::diesel_ext::BoxableFilter;
+#[cfg(feature = "postgres")]
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::scalar::WundergraphScalarValue;
+#[cfg(feature = "postgres")]
+use diesel::expression::{AsExpression, NonAggregate};
+#[cfg(feature = "postgres")]
+use diesel::pg::expression::operators::{Contains, IsContainedBy, OverlapsWith};
+#[cfg(feature = "postgres")]
+use diesel::pg::Pg;
+#[cfg(feature = "postgres")]
+use diesel::query_builder::QueryFragment;
+#[cfg(feature = "postgres")]
+use diesel::sql_types::Bool;
+#[cfg(feature = "postgres")]
+use diesel::{AppearsOnTable, Column};
+use juniper::{InputValue, ToInputValue};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone, Copy)]
+pub(super) enum ArrayOperator {
+    Contains,
+    ContainedBy,
+    Overlaps,
+}
+
+/// A filter comparing an array column with a given list of values
+///
+/// Depending on the operator this compiles down to `column @> values`,
+/// `column <@ values` or `column && values`
+#[derive(Debug)]
+pub struct ArrayComparison<T, C> {
+    values: Option<Vec<T>>,
+    operator: ArrayOperator,
+    p: PhantomData<C>,
+}
+
+impl<T, C> ArrayComparison<T, C> {
+    pub(super) fn new(values: Option<Vec<T>>, operator: ArrayOperator) -> Self {
+        Self {
+            values,
+            operator,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<T, C> Clone for ArrayComparison<T, C>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            operator: self.operator,
+            p: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<T, C> BuildFilter<Pg> for ArrayComparison<T, C>
+where
+    C: Column + AppearsOnTable<C::Table> + NonAggregate + QueryFragment<Pg> + Default + 'static,
+    C::Table: 'static,
+    Vec<T>: AsExpression<C::SqlType>,
+    <Vec<T> as AsExpression<C::SqlType>>::Expression:
+        NonAggregate + AppearsOnTable<C::Table> + QueryFragment<Pg> + 'static,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, Pg, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let operator = self.operator;
+        self.values.map(|values| {
+            let column = C::default();
+            let values = values.as_expression();
+            match operator {
+                ArrayOperator::Contains => Box::new(Contains::new(column, values)) as Self::Ret,
+                ArrayOperator::ContainedBy => {
+                    Box::new(IsContainedBy::new(column, values)) as Self::Ret
+                }
+                ArrayOperator::Overlaps => Box::new(OverlapsWith::new(column, values)) as Self::Ret,
+            }
+        })
+    }
+}
+
+impl<T, C> ToInputValue<WundergraphScalarValue> for ArrayComparison<T, C>
+where
+    T: ToInputValue<WundergraphScalarValue>,
+{
+    fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
+        self.values.to_input_value()
+    }
+}
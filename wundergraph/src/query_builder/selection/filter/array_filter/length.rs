@@ -0,0 +1,95 @@
+#[cfg(feature = "postgres")]
+use crate::diesel_ext::BoxableFilter;
+#[cfg(feature = "postgres")]
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::filter::count_filter::CountComparison;
+#[cfg(feature = "postgres")]
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+#[cfg(feature = "postgres")]
+use diesel::pg::Pg;
+#[cfg(feature = "postgres")]
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+#[cfg(feature = "postgres")]
+use diesel::sql_types::{Bool, Integer};
+#[cfg(feature = "postgres")]
+use diesel::{Column, QueryResult};
+use std::marker::PhantomData;
+
+/// The number of elements of an array column
+///
+/// Compiles down to `cardinality(column)`
+#[cfg(feature = "postgres")]
+#[derive(Debug, Clone, Copy)]
+pub struct Cardinality<C>(C);
+
+#[cfg(feature = "postgres")]
+impl<C> Expression for Cardinality<C>
+where
+    C: Expression,
+{
+    type SqlType = Integer;
+}
+
+#[cfg(feature = "postgres")]
+impl<C> NonAggregate for Cardinality<C> where C: NonAggregate {}
+
+#[cfg(feature = "postgres")]
+impl<C, QS> AppearsOnTable<QS> for Cardinality<C>
+where
+    C: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+#[cfg(feature = "postgres")]
+impl<C, QS> SelectableExpression<QS> for Cardinality<C>
+where
+    C: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+#[cfg(feature = "postgres")]
+impl<C> QueryId for Cardinality<C> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+#[cfg(feature = "postgres")]
+impl<C> QueryFragment<Pg> for Cardinality<C>
+where
+    C: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, Pg>) -> QueryResult<()> {
+        pass.push_sql("cardinality(");
+        self.0.walk_ast(pass.reborrow())?;
+        pass.push_sql(")");
+        Ok(())
+    }
+}
+
+/// Applies a `CountComparison` to the number of elements of the column `C`
+#[derive(Debug)]
+#[cfg_attr(not(feature = "postgres"), allow(dead_code))]
+pub struct LengthFilter<C>(CountComparison, PhantomData<C>);
+
+impl<C> LengthFilter<C> {
+    pub(super) fn new(comparison: CountComparison) -> Self {
+        Self(comparison, PhantomData)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<C> BuildFilter<Pg> for LengthFilter<C>
+where
+    C: Column + NonAggregate + QueryFragment<Pg> + Default + 'static,
+    C::Table: 'static,
+    Cardinality<C>: AppearsOnTable<C::Table, SqlType = Integer>,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, Pg, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        self.0
+            .into_filter::<_, i32, _, _>(|| Cardinality(C::default()))
+    }
+}
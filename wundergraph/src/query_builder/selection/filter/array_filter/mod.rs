@@ -0,0 +1,187 @@
+use crate::diesel_ext::BoxableFilter;
+use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::filter::collector::{AndCollector, FilterCollector};
+use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::sql_types::Bool;
+use diesel::Column;
+use indexmap::IndexMap;
+use juniper::meta::Argument;
+use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, ToInputValue};
+
+mod comparison;
+mod length;
+
+use self::comparison::{ArrayComparison, ArrayOperator};
+use self::length::LengthFilter;
+use super::count_filter::CountComparison;
+
+/// Additional filter operations for array columns
+///
+/// Those operations are only supported by the postgres backend
+#[derive(Debug)]
+pub struct ArrayFilter<T, C> {
+    contains: ArrayComparison<T, C>,
+    contained_by: ArrayComparison<T, C>,
+    overlaps: ArrayComparison<T, C>,
+    any_eq: Option<T>,
+    length: Option<CountComparison>,
+}
+
+impl<T, C> Clone for ArrayFilter<T, C>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            contains: self.contains.clone(),
+            contained_by: self.contained_by.clone(),
+            overlaps: self.overlaps.clone(),
+            any_eq: self.any_eq.clone(),
+            length: self.length.clone(),
+        }
+    }
+}
+
+impl<T, C> Nameable for ArrayFilter<T, C> {
+    fn name() -> String {
+        String::new()
+    }
+}
+
+impl<T, C, DB> BuildFilter<DB> for ArrayFilter<T, C>
+where
+    DB: Backend + 'static,
+    T: 'static,
+    C: Column,
+    C::Table: 'static,
+    ArrayComparison<T, C>:
+        BuildFilter<DB, Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>> + 'static,
+    LengthFilter<C>:
+        BuildFilter<DB, Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>> + 'static,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let mut combinator = AndCollector::default();
+        combinator.append_filter(self.contains);
+        combinator.append_filter(self.contained_by);
+        combinator.append_filter(self.overlaps);
+        combinator.append_filter(ArrayComparison::new(
+            self.any_eq.map(|v| vec![v]),
+            ArrayOperator::Contains,
+        ));
+        combinator.append_filter(self.length.map(LengthFilter::<C>::new));
+        combinator.into_filter()
+    }
+}
+
+fn values_from_input_value<T>(
+    obj: &IndexMap<&str, &InputValue<WundergraphScalarValue>>,
+    name: &str,
+) -> Option<Option<Vec<T>>>
+where
+    T: FromInputValue<WundergraphScalarValue>,
+{
+    obj.get(name).map_or_else(
+        || Option::from_input_value(&InputValue::Null),
+        |v| Option::from_input_value(*v),
+    )
+}
+
+fn values_from_look_ahead<T>(
+    obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    name: &str,
+) -> Option<Vec<T>>
+where
+    T: FromLookAheadValue,
+{
+    obj.iter()
+        .find(|o| o.0 == name)
+        .and_then(|o| Vec::from_look_ahead(&o.1))
+}
+
+impl<T, C> InnerFilter for ArrayFilter<T, C>
+where
+    T: GraphQLType<WundergraphScalarValue, TypeInfo = ()>
+        + FromInputValue<WundergraphScalarValue>
+        + ToInputValue<WundergraphScalarValue>
+        + FromLookAheadValue
+        + Clone,
+{
+    type Context = ();
+
+    const FIELD_COUNT: usize = 5;
+
+    fn from_inner_input_value(
+        obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
+    ) -> Option<Self> {
+        let contains = values_from_input_value(&obj, "contains")?;
+        let contained_by = values_from_input_value(&obj, "contained_by")?;
+        let overlaps = values_from_input_value(&obj, "overlaps")?;
+        let any_eq = obj.get("any_eq").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        let length = obj.get("length").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        Some(Self {
+            contains: ArrayComparison::new(contains, ArrayOperator::Contains),
+            contained_by: ArrayComparison::new(contained_by, ArrayOperator::ContainedBy),
+            overlaps: ArrayComparison::new(overlaps, ArrayOperator::Overlaps),
+            any_eq,
+            length,
+        })
+    }
+
+    fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
+        let any_eq = obj
+            .iter()
+            .find(|o| o.0 == "any_eq")
+            .and_then(|o| T::from_look_ahead(&o.1));
+        let length = obj
+            .iter()
+            .find(|o| o.0 == "length")
+            .and_then(|o| CountComparison::from_look_ahead(&o.1));
+        Self {
+            contains: ArrayComparison::new(
+                values_from_look_ahead(obj, "contains"),
+                ArrayOperator::Contains,
+            ),
+            contained_by: ArrayComparison::new(
+                values_from_look_ahead(obj, "contained_by"),
+                ArrayOperator::ContainedBy,
+            ),
+            overlaps: ArrayComparison::new(
+                values_from_look_ahead(obj, "overlaps"),
+                ArrayOperator::Overlaps,
+            ),
+            any_eq,
+            length,
+        }
+    }
+
+    fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
+        map.insert("contains", self.contains.to_input_value());
+        map.insert("contained_by", self.contained_by.to_input_value());
+        map.insert("overlaps", self.overlaps.to_input_value());
+        map.insert("any_eq", self.any_eq.to_input_value());
+        map.insert("length", self.length.to_input_value());
+    }
+
+    fn register_fields<'r>(
+        _info: &NameBuilder<Self>,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+        let contains = registry.arg_with_default::<Option<Vec<T>>>("contains", &None, &());
+        let contained_by = registry.arg_with_default::<Option<Vec<T>>>("contained_by", &None, &());
+        let overlaps = registry.arg_with_default::<Option<Vec<T>>>("overlaps", &None, &());
+        let any_eq = registry.arg_with_default::<Option<T>>("any_eq", &None, &());
+        let length = registry.arg_with_default::<Option<CountComparison>>("length", &None, &());
+        vec![contains, contained_by, overlaps, any_eq, length]
+    }
+}
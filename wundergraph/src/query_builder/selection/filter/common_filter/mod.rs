@@ -18,10 +18,18 @@ use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry,
 
 mod eq;
 mod eq_any;
+mod gt;
+mod gt_eq;
+mod lt;
+mod lt_eq;
 mod not_eq;
 
 use self::eq::Eq;
 use self::eq_any::EqAny;
+use self::gt::Gt;
+use self::gt_eq::GtEq;
+use self::lt::Lt;
+use self::lt_eq::LtEq;
 use self::not_eq::NotEq;
 
 /// This struct summarize all possible filter operations for a given graphql
@@ -41,7 +49,10 @@ where
     eq: Eq<T::RawValue, C>,
     neq: NotEq<T::RawValue, C>,
     eq_any: EqAny<T::RawValue, C>,
-    // TODO: implement more
+    gt: Gt<T::RawValue, C>,
+    gte: GtEq<T::RawValue, C>,
+    lt: Lt<T::RawValue, C>,
+    lte: LtEq<T::RawValue, C>,
     additional: T::AdditionalFilter,
 }
 
@@ -55,6 +66,10 @@ where
             eq: self.eq.clone(),
             neq: self.neq.clone(),
             eq_any: self.eq_any.clone(),
+            gt: self.gt.clone(),
+            gte: self.gte.clone(),
+            lt: self.lt.clone(),
+            lte: self.lte.clone(),
             additional: self.additional.clone(),
         }
     }
@@ -73,7 +88,7 @@ where
 {
     type Context = V::Context;
 
-    const FIELD_COUNT: usize = 3 + V::AdditionalFilter::FIELD_COUNT;
+    const FIELD_COUNT: usize = 7 + V::AdditionalFilter::FIELD_COUNT;
 
     fn from_inner_input_value(
         obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
@@ -90,11 +105,31 @@ where
             || Option::from_input_value(&InputValue::Null),
             |v| Option::from_input_value(*v),
         )?);
+        let gt = Gt::new(obj.get("gt").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?);
+        let gte = GtEq::new(obj.get("gte").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?);
+        let lt = Lt::new(obj.get("lt").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?);
+        let lte = LtEq::new(obj.get("lte").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?);
         let additional = V::AdditionalFilter::from_inner_input_value(obj)?;
         Some(Self {
             eq,
             neq,
             eq_any,
+            gt,
+            gte,
+            lt,
+            lte,
             additional,
         })
     }
@@ -118,12 +153,40 @@ where
             .and_then(|o| Vec::from_look_ahead(&o.1));
         let eq_any = EqAny::new(eq_any);
 
+        let gt = obj
+            .iter()
+            .find(|o| o.0 == "gt")
+            .and_then(|o| V::RawValue::from_look_ahead(&o.1));
+        let gt = Gt::new(gt);
+
+        let gte = obj
+            .iter()
+            .find(|o| o.0 == "gte")
+            .and_then(|o| V::RawValue::from_look_ahead(&o.1));
+        let gte = GtEq::new(gte);
+
+        let lt = obj
+            .iter()
+            .find(|o| o.0 == "lt")
+            .and_then(|o| V::RawValue::from_look_ahead(&o.1));
+        let lt = Lt::new(lt);
+
+        let lte = obj
+            .iter()
+            .find(|o| o.0 == "lte")
+            .and_then(|o| V::RawValue::from_look_ahead(&o.1));
+        let lte = LtEq::new(lte);
+
         let additional = V::AdditionalFilter::from_inner_look_ahead(obj);
 
         Self {
             eq,
             neq,
             eq_any,
+            gt,
+            gte,
+            lt,
+            lte,
             additional,
         }
     }
@@ -132,6 +195,10 @@ where
         map.insert("eq", self.eq.to_input_value());
         map.insert("not_eq", self.neq.to_input_value());
         map.insert("eq_any", self.eq_any.to_input_value());
+        map.insert("gt", self.gt.to_input_value());
+        map.insert("gte", self.gte.to_input_value());
+        map.insert("lt", self.lt.to_input_value());
+        map.insert("lte", self.lte.to_input_value());
         self.additional.to_inner_input_value(map);
     }
 
@@ -143,7 +210,11 @@ where
         let neq = registry.arg_with_default::<Option<V>>("not_eq", &None, &Default::default());
         let eq_any =
             registry.arg_with_default::<Option<Vec<V>>>("eq_any", &None, &Default::default());
-        let mut ret = vec![eq, neq, eq_any];
+        let gt = registry.arg_with_default::<Option<V>>("gt", &None, &Default::default());
+        let gte = registry.arg_with_default::<Option<V>>("gte", &None, &Default::default());
+        let lt = registry.arg_with_default::<Option<V>>("lt", &None, &Default::default());
+        let lte = registry.arg_with_default::<Option<V>>("lte", &None, &Default::default());
+        let mut ret = vec![eq, neq, eq_any, gt, gte, lt, lte];
         let additional = V::AdditionalFilter::register_fields(&NameBuilder::default(), registry);
         ret.extend(additional);
         ret
@@ -170,7 +241,7 @@ where
     Self: InnerFilter,
 {
     fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
-        let mut map = IndexMap::with_capacity(3);
+        let mut map = IndexMap::with_capacity(Self::FIELD_COUNT);
         self.to_inner_input_value(&mut map);
         InputValue::object(map)
     }
@@ -246,6 +317,14 @@ where
         AppearsOnTable<C::Table, SqlType = Bool>,
     In<C, Many<<T::RawValue as AsExpression<C::SqlType>>::Expression>>:
         AppearsOnTable<C::Table, SqlType = Bool>,
+    operators::Gt<C, <T::RawValue as AsExpression<C::SqlType>>::Expression>:
+        AppearsOnTable<C::Table, SqlType = Bool>,
+    operators::GtEq<C, <T::RawValue as AsExpression<C::SqlType>>::Expression>:
+        AppearsOnTable<C::Table, SqlType = Bool>,
+    operators::Lt<C, <T::RawValue as AsExpression<C::SqlType>>::Expression>:
+        AppearsOnTable<C::Table, SqlType = Bool>,
+    operators::LtEq<C, <T::RawValue as AsExpression<C::SqlType>>::Expression>:
+        AppearsOnTable<C::Table, SqlType = Bool>,
 {
     type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
 
@@ -254,6 +333,10 @@ where
         combinator.append_filter(self.eq);
         combinator.append_filter(self.neq);
         combinator.append_filter(self.eq_any);
+        combinator.append_filter(self.gt);
+        combinator.append_filter(self.gte);
+        combinator.append_filter(self.lt);
+        combinator.append_filter(self.lte);
         combinator.append_filter(self.additional);
         combinator.into_filter()
     }
@@ -0,0 +1,245 @@
+use crate::diesel_ext::BoxableFilter;
+use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::filter::collector::{AndCollector, FilterCollector};
+use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::scalar::WundergraphScalarValue;
+use diesel::associations::HasTable;
+use diesel::backend::Backend;
+use diesel::expression::{
+    AppearsOnTable, AsExpression, Expression, NonAggregate, SelectableExpression,
+};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::serialize::ToSql;
+use diesel::sql_types::{BigInt, Bool, HasSqlType};
+use diesel::{dsl, Column, ExpressionMethods, QueryResult, QuerySource};
+use indexmap::IndexMap;
+use juniper::meta::Argument;
+use juniper::{
+    FromInputValue, GraphQLInputObject, InputValue, LookAheadValue, Registry, ToInputValue,
+};
+use std::marker::PhantomData;
+
+type CountRet<T, DB> = Box<dyn BoxableFilter<T, DB, SqlType = Bool>>;
+
+/// Counts the rows of a related table referencing the current row
+///
+/// Compiles down to `(SELECT COUNT(*) FROM other WHERE other.fk = pk)`
+#[derive(Debug)]
+pub struct RelatedCount<C, C2> {
+    p: PhantomData<(C, C2)>,
+}
+
+impl<C, C2> Default for RelatedCount<C, C2> {
+    fn default() -> Self {
+        Self { p: PhantomData }
+    }
+}
+
+impl<C, C2> Clone for RelatedCount<C, C2> {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl<C, C2> Expression for RelatedCount<C, C2> {
+    type SqlType = BigInt;
+}
+
+impl<C, C2> NonAggregate for RelatedCount<C, C2> {}
+
+impl<C, C2, QS> AppearsOnTable<QS> for RelatedCount<C, C2> where C: AppearsOnTable<QS> {}
+
+impl<C, C2, QS> SelectableExpression<QS> for RelatedCount<C, C2> where C: SelectableExpression<QS> {}
+
+impl<C, C2> QueryId for RelatedCount<C, C2> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<C, C2, DB> QueryFragment<DB> for RelatedCount<C, C2>
+where
+    DB: Backend,
+    C: QueryFragment<DB> + Default,
+    C2: Column + QueryFragment<DB> + Default,
+    C2::Table: HasTable<Table = C2::Table>,
+    <C2::Table as QuerySource>::FromClause: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, DB>) -> QueryResult<()> {
+        pass.push_sql("(SELECT COUNT(*) FROM ");
+        C2::Table::table().from_clause().walk_ast(pass.reborrow())?;
+        pass.push_sql(" WHERE ");
+        C2::default().walk_ast(pass.reborrow())?;
+        pass.push_sql(" = ");
+        C::default().walk_ast(pass.reborrow())?;
+        pass.push_sql(")");
+        Ok(())
+    }
+}
+
+/// Comparisons on a number of elements
+///
+/// Shared by the `length` filter of array columns and the
+/// `count` filter of `HasMany` relations
+#[derive(Debug, Clone, GraphQLInputObject)]
+#[graphql(name = "CountComparison", scalar = "WundergraphScalarValue")]
+pub struct CountComparison {
+    eq: Option<i32>,
+    not_eq: Option<i32>,
+    gt: Option<i32>,
+    gte: Option<i32>,
+    lt: Option<i32>,
+    lte: Option<i32>,
+}
+
+impl FromLookAheadValue for CountComparison {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Object(ref obj) = *v {
+            let get = |name: &str| {
+                obj.iter()
+                    .find(|o| o.0 == name)
+                    .and_then(|o| i32::from_look_ahead(&o.1))
+            };
+            Some(Self {
+                eq: get("eq"),
+                not_eq: get("not_eq"),
+                gt: get("gt"),
+                gte: get("gte"),
+                lt: get("lt"),
+                lte: get("lte"),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl CountComparison {
+    /// Compares the number returned by `count` with all given values
+    ///
+    /// `V` is the rust type matching the sql type of the counted expression
+    pub(crate) fn into_filter<E, V, T, DB>(self, count: impl Fn() -> E) -> Option<CountRet<T, DB>>
+    where
+        DB: Backend + 'static,
+        T: 'static,
+        E: ExpressionMethods,
+        V: From<i32> + AsExpression<E::SqlType>,
+        dsl::Eq<E, V>: BoxableFilter<T, DB, SqlType = Bool> + 'static,
+        dsl::NotEq<E, V>: BoxableFilter<T, DB, SqlType = Bool> + 'static,
+        dsl::Gt<E, V>: BoxableFilter<T, DB, SqlType = Bool> + 'static,
+        dsl::GtEq<E, V>: BoxableFilter<T, DB, SqlType = Bool> + 'static,
+        dsl::Lt<E, V>: BoxableFilter<T, DB, SqlType = Bool> + 'static,
+        dsl::LtEq<E, V>: BoxableFilter<T, DB, SqlType = Bool> + 'static,
+    {
+        let mut and = AndCollector::default();
+        and.append_filter(
+            self.eq
+                .map(|v| Box::new(count().eq(V::from(v))) as CountRet<T, DB>),
+        );
+        and.append_filter(
+            self.not_eq
+                .map(|v| Box::new(count().ne(V::from(v))) as CountRet<T, DB>),
+        );
+        and.append_filter(
+            self.gt
+                .map(|v| Box::new(count().gt(V::from(v))) as CountRet<T, DB>),
+        );
+        and.append_filter(
+            self.gte
+                .map(|v| Box::new(count().ge(V::from(v))) as CountRet<T, DB>),
+        );
+        and.append_filter(
+            self.lt
+                .map(|v| Box::new(count().lt(V::from(v))) as CountRet<T, DB>),
+        );
+        and.append_filter(
+            self.lte
+                .map(|v| Box::new(count().le(V::from(v))) as CountRet<T, DB>),
+        );
+        and.into_filter()
+    }
+}
+
+/// Additional filter operation for `HasMany` relations
+///
+/// `count` compares the number of related entities
+#[derive(Debug)]
+pub struct CountFilter<C, C2> {
+    count: Option<CountComparison>,
+    p: PhantomData<(C, C2)>,
+}
+
+impl<C, C2> Clone for CountFilter<C, C2> {
+    fn clone(&self) -> Self {
+        Self {
+            count: self.count.clone(),
+            p: PhantomData,
+        }
+    }
+}
+
+impl<C, C2> Nameable for CountFilter<C, C2> {
+    fn name() -> String {
+        String::new()
+    }
+}
+
+impl<C, C2, DB> BuildFilter<DB> for CountFilter<C, C2>
+where
+    DB: Backend + HasSqlType<BigInt> + 'static,
+    i64: ToSql<BigInt, DB>,
+    C: Column + AppearsOnTable<C::Table> + QueryFragment<DB> + Default + 'static,
+    C::Table: 'static,
+    C2: Column + QueryFragment<DB> + Default + 'static,
+    C2::Table: HasTable<Table = C2::Table>,
+    <C2::Table as QuerySource>::FromClause: QueryFragment<DB>,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        self.count
+            .and_then(|count| count.into_filter::<_, i64, _, _>(RelatedCount::<C, C2>::default))
+    }
+}
+
+impl<C, C2> InnerFilter for CountFilter<C, C2> {
+    type Context = ();
+
+    const FIELD_COUNT: usize = 1;
+
+    fn from_inner_input_value(
+        obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
+    ) -> Option<Self> {
+        let count = obj.get("count").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        Some(Self {
+            count,
+            p: PhantomData,
+        })
+    }
+
+    fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
+        let count = obj
+            .iter()
+            .find(|o| o.0 == "count")
+            .and_then(|o| CountComparison::from_look_ahead(&o.1));
+        Self {
+            count,
+            p: PhantomData,
+        }
+    }
+
+    fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
+        map.insert("count", self.count.to_input_value());
+    }
+
+    fn register_fields<'r>(
+        _info: &NameBuilder<Self>,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+        let count = registry.arg_with_default::<Option<CountComparison>>("count", &None, &());
+        vec![count]
+    }
+}
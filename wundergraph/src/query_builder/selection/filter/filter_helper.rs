@@ -1,8 +1,10 @@
 use super::build_filter::BuildFilter;
 use super::common_filter::FilterOption;
+use super::count_filter::CountFilter;
 use super::filter_value::FilterValue;
 use super::inner_filter::InnerFilter;
 use super::nullable_filter::IsNull;
+use super::quantified_filter::QuantifiedFilter;
 use super::reference_filter::ReferenceFilter;
 use super::Filter;
 use crate::diesel_ext::BoxableFilter;
@@ -130,8 +132,15 @@ where
     <O::Table as QuerySource>::FromClause: QueryFragment<DB>,
     DB::QueryBuilder: Default,
 {
-    type Filter =
-        ReferenceFilter<<L::Table as Table>::PrimaryKey, Filter<O::Filter, O::Table>, FK, ()>;
+    type Filter = ReferenceFilter<
+        <L::Table as Table>::PrimaryKey,
+        Filter<O::Filter, O::Table>,
+        FK,
+        (
+            QuantifiedFilter<<L::Table as Table>::PrimaryKey, Filter<O::Filter, O::Table>, FK>,
+            CountFilter<<L::Table as Table>::PrimaryKey, FK>,
+        ),
+    >;
 }
 
 impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for i16 {
@@ -233,7 +242,7 @@ where
 ///
 /// # Type paramaters
 /// * `Self`:
-/// * `DB`: Backend from diesel, so one of `Pg` or `Sqlite`
+/// * `DB`: Backend from diesel, so one of `Pg`, `Sqlite` or `Mysql`
 /// * `F`:
 /// * `Ctx`: The used wundergraph context type
 ///
@@ -334,7 +343,9 @@ where
 ///      fn to_inner_input_value(
 ///          f: &Filter<Pg, Ctx>,
 ///          v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>
-///      ) {}
+///      ) {
+///          FilterBuildHelper::<Filter<Pg, Ctx>, Hero, Pg, Ctx>::inner_to_input_value(f, v)
+///      }
 ///
 ///      fn register_fields<'r>(
 ///          info: &NameBuilder<()>,
@@ -484,6 +495,26 @@ macro_rules! __impl_build_filter_for_tuples {
                 }
             }
 
+            impl<$($T,)* Loading, Back, Ctx> FilterBuildHelper<($(Option<$T>,)*), Loading, Back, Ctx>
+            where Back: Backend + ApplyOffset + 'static,
+                Loading::Table: 'static,
+                Loading: LoadingHandler<Back, Ctx>,
+                <Loading::Table as QuerySource>::FromClause: QueryFragment<Back>,
+                Back::QueryBuilder: Default,
+                $($T: ToInputValue<WundergraphScalarValue>,)*
+            {
+                /// Serialize the given filter values into a juniper input value
+                pub fn inner_to_input_value(
+                    inner: &($(Option<$T>,)*),
+                    v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>
+                ) {
+                    $(
+                        let value = <Option<$T> as ToInputValue<WundergraphScalarValue>>::to_input_value(&inner.$idx);
+                        v.insert(Loading::FIELD_NAMES[$idx], value);
+                    )*
+                }
+            }
+
             impl<$($T,)* Loading, Back, Ctx> InnerFilter for FilterBuildHelper<($(Option<$T>,)*), Loading, Back, Ctx>
             where Back: Backend + ApplyOffset + 'static,
                 Loading::Table: 'static,
@@ -533,11 +564,7 @@ macro_rules! __impl_build_filter_for_tuples {
                 fn to_inner_input_value(
                     &self, v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>
                 ) {
-                    let inner = &self.0;
-                    $(
-                        let value = <Option<$T> as ToInputValue<WundergraphScalarValue>>::to_input_value(&inner.$idx);
-                        v.insert(Loading::FIELD_NAMES[$idx], value);
-                    )*
+                    Self::inner_to_input_value(&self.0, v)
                 }
 
                 fn register_fields<'r>(
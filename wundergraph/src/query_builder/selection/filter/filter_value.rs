@@ -1,5 +1,7 @@
 use crate::juniper_ext::FromLookAheadValue;
+use crate::query_builder::selection::filter::array_filter::ArrayFilter;
 use crate::query_builder::selection::filter::nullable_filter::NullableFilter;
+use crate::query_builder::selection::filter::range_filter::RangeFilter;
 use crate::query_builder::selection::filter::string_filter::StringFilter;
 use crate::scalar::WundergraphScalarValue;
 use juniper::{FromInputValue, ToInputValue};
@@ -28,17 +30,17 @@ pub trait FilterValue<C> {
 
 impl<C> FilterValue<C> for i16 {
     type RawValue = Self;
-    type AdditionalFilter = ();
+    type AdditionalFilter = RangeFilter<Self, C>;
 }
 
 impl<C> FilterValue<C> for i32 {
     type RawValue = Self;
-    type AdditionalFilter = ();
+    type AdditionalFilter = RangeFilter<Self, C>;
 }
 
 impl<C> FilterValue<C> for i64 {
     type RawValue = Self;
-    type AdditionalFilter = ();
+    type AdditionalFilter = RangeFilter<Self, C>;
 }
 
 impl<C> FilterValue<C> for String {
@@ -53,12 +55,12 @@ impl<C> FilterValue<C> for bool {
 
 impl<C> FilterValue<C> for f32 {
     type RawValue = Self;
-    type AdditionalFilter = ();
+    type AdditionalFilter = RangeFilter<Self, C>;
 }
 
 impl<C> FilterValue<C> for f64 {
     type RawValue = Self;
-    type AdditionalFilter = ();
+    type AdditionalFilter = RangeFilter<Self, C>;
 }
 
 impl<C, V> FilterValue<C> for Vec<V>
@@ -70,7 +72,7 @@ where
         + Clone,
 {
     type RawValue = Self;
-    type AdditionalFilter = ();
+    type AdditionalFilter = ArrayFilter<V, C>;
 }
 
 impl<V, C> FilterValue<C> for Option<V>
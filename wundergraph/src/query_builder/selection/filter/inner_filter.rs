@@ -52,3 +52,39 @@ impl InnerFilter for () {
         vec![]
     }
 }
+
+impl<A, B> InnerFilter for (A, B)
+where
+    A: InnerFilter,
+    B: InnerFilter,
+{
+    type Context = ();
+
+    const FIELD_COUNT: usize = A::FIELD_COUNT + B::FIELD_COUNT;
+
+    fn from_inner_input_value(
+        v: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
+    ) -> Option<Self> {
+        let a = A::from_inner_input_value(v.clone())?;
+        let b = B::from_inner_input_value(v)?;
+        Some((a, b))
+    }
+
+    fn from_inner_look_ahead(v: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
+        (A::from_inner_look_ahead(v), B::from_inner_look_ahead(v))
+    }
+
+    fn to_inner_input_value(&self, v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
+        self.0.to_inner_input_value(v);
+        self.1.to_inner_input_value(v);
+    }
+
+    fn register_fields<'r>(
+        _info: &NameBuilder<Self>,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+        let mut fields = A::register_fields(&NameBuilder::default(), registry);
+        fields.extend(B::register_fields(&NameBuilder::default(), registry));
+        fields
+    }
+}
@@ -0,0 +1,86 @@
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use diesel::pg::Pg;
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+use diesel::sql_types::{Array, Bool, Jsonb, Text};
+use serde_json_internal::Value;
+
+#[derive(Debug, Clone)]
+pub(super) enum JsonOperator {
+    HasKey(String),
+    Contains(Value),
+    PathEq(Vec<String>, Value),
+}
+
+/// A filter expression on a `json` or `jsonb` column
+///
+/// The column is always casted to `jsonb`, so this compiles down to
+/// `CAST(column AS jsonb) ? key`, `CAST(column AS jsonb) @> value`
+/// or `CAST(column AS jsonb) #> path = value`
+#[derive(Debug, Clone)]
+pub struct JsonOperation<C> {
+    column: C,
+    operator: JsonOperator,
+}
+
+impl<C> JsonOperation<C> {
+    pub(super) fn new(column: C, operator: JsonOperator) -> Self {
+        Self { column, operator }
+    }
+}
+
+impl<C> Expression for JsonOperation<C>
+where
+    C: Expression,
+{
+    type SqlType = Bool;
+}
+
+impl<C> NonAggregate for JsonOperation<C> where C: NonAggregate {}
+
+impl<C, QS> AppearsOnTable<QS> for JsonOperation<C>
+where
+    C: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+impl<C, QS> SelectableExpression<QS> for JsonOperation<C>
+where
+    C: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl<C> QueryId for JsonOperation<C> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<C> QueryFragment<Pg> for JsonOperation<C>
+where
+    C: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, Pg>) -> QueryResult<()> {
+        pass.push_sql("CAST(");
+        self.column.walk_ast(pass.reborrow())?;
+        pass.push_sql(" AS jsonb)");
+        match self.operator {
+            JsonOperator::HasKey(ref key) => {
+                pass.push_sql(" ? ");
+                pass.push_bind_param::<Text, _>(key)?;
+            }
+            JsonOperator::Contains(ref value) => {
+                pass.push_sql(" @> ");
+                pass.push_bind_param::<Jsonb, _>(value)?;
+            }
+            JsonOperator::PathEq(ref path, ref value) => {
+                pass.push_sql(" #> ");
+                pass.push_bind_param::<Array<Text>, _>(path)?;
+                pass.push_sql(" = ");
+                pass.push_bind_param::<Jsonb, _>(value)?;
+            }
+        }
+        Ok(())
+    }
+}
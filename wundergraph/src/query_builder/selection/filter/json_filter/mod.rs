@@ -0,0 +1,243 @@
+use crate::diesel_ext::BoxableFilter;
+use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::filter::collector::{AndCollector, FilterCollector};
+use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::query_builder::selection::filter::nullable_filter::IsNull;
+use crate::scalar::WundergraphScalarValue;
+use crate::third_party_integrations::serde_json::json_from_look_ahead;
+use diesel::expression::{AppearsOnTable, NonAggregate};
+use diesel::pg::Pg;
+use diesel::query_builder::QueryFragment;
+use diesel::sql_types::Bool;
+use diesel::Column;
+use indexmap::IndexMap;
+use juniper::meta::{Argument, MetaType};
+use juniper::{
+    FromInputValue, GraphQLInputObject, GraphQLType, InputValue, LookAheadValue, Registry,
+    ToInputValue,
+};
+use serde_json_internal::Value;
+use std::marker::PhantomData;
+
+mod expression;
+
+use self::expression::{JsonOperation, JsonOperator};
+
+/// Compares the json value at a given path with a given value
+#[derive(Debug, Clone, GraphQLInputObject)]
+#[graphql(name = "JsonPathEq", scalar = "WundergraphScalarValue")]
+pub struct JsonPathEq {
+    /// Path of the compared value, given as list of object keys
+    /// and array indices
+    path: Vec<String>,
+    /// The expected value
+    value: Value,
+}
+
+impl FromLookAheadValue for JsonPathEq {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Object(ref obj) = *v {
+            let path = obj
+                .iter()
+                .find(|o| o.0 == "path")
+                .and_then(|o| Vec::from_look_ahead(&o.1))?;
+            let value = obj
+                .iter()
+                .find(|o| o.0 == "value")
+                .and_then(|o| json_from_look_ahead(&o.1))?;
+            Some(Self { path, value })
+        } else {
+            None
+        }
+    }
+}
+
+/// Filter operations for `json` and `jsonb` columns
+///
+/// Json values are not comparable, so this filter replaces the default
+/// comparison operations. Those operations are only supported by the
+/// postgres backend
+#[derive(Debug)]
+pub struct JsonFilter<C> {
+    is_null: Option<IsNull<C>>,
+    has_key: Option<String>,
+    contains: Option<Value>,
+    path_eq: Option<JsonPathEq>,
+    p: PhantomData<C>,
+}
+
+impl<C> Clone for JsonFilter<C> {
+    fn clone(&self) -> Self {
+        Self {
+            is_null: self.is_null.clone(),
+            has_key: self.has_key.clone(),
+            contains: self.contains.clone(),
+            path_eq: self.path_eq.clone(),
+            p: PhantomData,
+        }
+    }
+}
+
+impl<C> Nameable for JsonFilter<C> {
+    fn name() -> String {
+        String::from("JsonFilter")
+    }
+}
+
+impl<C> BuildFilter<Pg> for JsonFilter<C>
+where
+    C: Column + AppearsOnTable<C::Table> + NonAggregate + QueryFragment<Pg> + Default + 'static,
+    C::Table: 'static,
+    IsNull<C>: BuildFilter<Pg, Ret = Box<dyn BoxableFilter<C::Table, Pg, SqlType = Bool>>>,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, Pg, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let operators = vec![
+            self.has_key.map(JsonOperator::HasKey),
+            self.contains.map(JsonOperator::Contains),
+            self.path_eq
+                .map(|path_eq| JsonOperator::PathEq(path_eq.path, path_eq.value)),
+        ];
+        let mut combinator = AndCollector::default();
+        combinator.append_filter(self.is_null);
+        for operator in operators.into_iter().flatten() {
+            combinator
+                .append_filter(Box::new(JsonOperation::new(C::default(), operator)) as Self::Ret);
+        }
+        combinator.into_filter()
+    }
+}
+
+impl<C> InnerFilter for JsonFilter<C> {
+    type Context = ();
+
+    const FIELD_COUNT: usize = 4;
+
+    fn from_inner_input_value(
+        obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
+    ) -> Option<Self> {
+        let is_null = match obj.get("is_null").map(|v| bool::from_input_value(v)) {
+            Some(Some(b)) => Some(IsNull::new(b)),
+            Some(None) => return None,
+            None => None,
+        };
+        let has_key = obj.get("has_key").map_or_else(
+            || Some(None),
+            |v| <Option<String> as FromInputValue<WundergraphScalarValue>>::from_input_value(*v),
+        )?;
+        let contains = obj.get("contains").map_or_else(
+            || Some(None),
+            |v| <Option<Value> as FromInputValue<WundergraphScalarValue>>::from_input_value(*v),
+        )?;
+        let path_eq = obj.get("path_eq").map_or_else(
+            || Some(None),
+            |v| {
+                <Option<JsonPathEq> as FromInputValue<WundergraphScalarValue>>::from_input_value(*v)
+            },
+        )?;
+        Some(Self {
+            is_null,
+            has_key,
+            contains,
+            path_eq,
+            p: PhantomData,
+        })
+    }
+
+    fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
+        let is_null = obj
+            .iter()
+            .find(|o| o.0 == "is_null")
+            .and_then(|o| bool::from_look_ahead(&o.1))
+            .map(IsNull::new);
+        let has_key = obj
+            .iter()
+            .find(|o| o.0 == "has_key")
+            .and_then(|o| String::from_look_ahead(&o.1));
+        let contains = obj
+            .iter()
+            .find(|o| o.0 == "contains")
+            .and_then(|o| json_from_look_ahead(&o.1));
+        let path_eq = obj
+            .iter()
+            .find(|o| o.0 == "path_eq")
+            .and_then(|o| JsonPathEq::from_look_ahead(&o.1));
+        Self {
+            is_null,
+            has_key,
+            contains,
+            path_eq,
+            p: PhantomData,
+        }
+    }
+
+    fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
+        map.insert("is_null", self.is_null.to_input_value());
+        map.insert("has_key", self.has_key.to_input_value());
+        map.insert("contains", self.contains.to_input_value());
+        map.insert("path_eq", self.path_eq.to_input_value());
+    }
+
+    fn register_fields<'r>(
+        _info: &NameBuilder<Self>,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+        let is_null = registry.arg_with_default::<Option<bool>>("is_null", &None, &());
+        let has_key = registry.arg_with_default::<Option<String>>("has_key", &None, &());
+        let contains = registry.arg_with_default::<Option<Value>>("contains", &None, &());
+        let path_eq = registry.arg_with_default::<Option<JsonPathEq>>("path_eq", &None, &());
+        vec![is_null, has_key, contains, path_eq]
+    }
+}
+
+impl<C> FromInputValue<WundergraphScalarValue> for JsonFilter<C> {
+    fn from_input_value(v: &InputValue<WundergraphScalarValue>) -> Option<Self> {
+        if let Some(obj) = v.to_object_value() {
+            Self::from_inner_input_value(obj)
+        } else {
+            None
+        }
+    }
+}
+
+impl<C> ToInputValue<WundergraphScalarValue> for JsonFilter<C> {
+    fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
+        let mut map = IndexMap::with_capacity(Self::FIELD_COUNT);
+        self.to_inner_input_value(&mut map);
+        InputValue::object(map)
+    }
+}
+
+impl<C> FromLookAheadValue for JsonFilter<C> {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Object(ref obj) = *v {
+            Some(Self::from_inner_look_ahead(obj))
+        } else {
+            None
+        }
+    }
+}
+
+impl<C> GraphQLType<WundergraphScalarValue> for JsonFilter<C> {
+    type Context = ();
+    type TypeInfo = NameBuilder<Self>;
+
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        Some(info.name())
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let fields = Self::register_fields(info, registry);
+        registry
+            .build_input_object_type::<Self>(info, &fields)
+            .into_meta()
+    }
+}
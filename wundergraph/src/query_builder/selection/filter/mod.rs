@@ -2,6 +2,7 @@
 //! filter entities. The main entry point is the [`Filter`](struct.Filter.html) struct
 
 use crate::diesel_ext::BoxableFilter;
+use crate::error::{Result, WundergraphError};
 use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
 use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
@@ -20,14 +21,20 @@ use juniper::Registry;
 use juniper::ToInputValue;
 use std::marker::PhantomData;
 
+mod array_filter;
 pub(crate) mod build_filter;
 pub mod collector;
 mod common_filter;
+pub(crate) mod count_filter;
 pub(crate) mod filter_helper;
 pub(crate) mod filter_value;
 pub(crate) mod inner_filter;
+#[cfg(all(feature = "serde_json", feature = "postgres"))]
+pub(crate) mod json_filter;
 mod not;
 mod nullable_filter;
+mod quantified_filter;
+pub(crate) mod range_filter;
 mod reference_filter;
 mod string_filter;
 
@@ -196,6 +203,33 @@ impl<F, T> Filter<F, T>
 where
     F: InnerFilter,
 {
+    /// Create a filter from the graphql lookahead value of the argument `arg`
+    ///
+    /// In contrast to `FromLookAheadValue::from_look_ahead` this returns an
+    /// error if the argument is not an object. If `strict` is set, it also
+    /// returns an error naming the path of the first value that could not be
+    /// interpreted, instead of silently ignoring that part of the filter.
+    /// This relies on `InnerFilter::to_inner_input_value` writing back all
+    /// values understood by the filter, see `LoadingHandler::STRICT_FILTER`.
+    pub fn from_look_ahead_checked(
+        arg: &str,
+        v: &LookAheadValue<'_, WundergraphScalarValue>,
+        strict: bool,
+    ) -> Result<Self> {
+        let filter =
+            Self::from_look_ahead(v).ok_or_else(|| WundergraphError::InvalidFilterArgument {
+                path: arg.to_owned(),
+            })?;
+        if strict {
+            if let Some(dropped) = find_dropped_value(v, &filter.to_input_value()) {
+                return Err(WundergraphError::InvalidFilterArgument {
+                    path: format!("{}{}", arg, dropped),
+                });
+            }
+        }
+        Ok(filter)
+    }
+
     /// Apply the filter to a given select statement
     ///
     /// This function will extend the where clause with the given filter expression
@@ -301,3 +335,41 @@ where
         fields
     }
 }
+
+/// Returns the path of the first non null value in `look_ahead` that is
+/// missing in `parsed`
+///
+/// `parsed` is the input value of a filter constructed from `look_ahead`.
+/// Any value missing there was not understood while constructing the filter.
+fn find_dropped_value(
+    look_ahead: &LookAheadValue<'_, WundergraphScalarValue>,
+    parsed: &InputValue<WundergraphScalarValue>,
+) -> Option<String> {
+    match (look_ahead, parsed) {
+        (LookAheadValue::Null, _) => None,
+        (_, InputValue::Null) => Some(String::new()),
+        (LookAheadValue::Object(fields), InputValue::Object(parsed)) => {
+            fields.iter().find_map(|(name, value)| {
+                let parsed = parsed
+                    .iter()
+                    .find(|(k, _)| k.item == *name)
+                    .map_or(&InputValue::Null, |(_, v)| &v.item);
+                find_dropped_value(value, parsed).map(|p| format!(".{}{}", name, p))
+            })
+        }
+        (LookAheadValue::List(values), InputValue::List(parsed)) => {
+            if values.len() == parsed.len() {
+                values
+                    .iter()
+                    .zip(parsed)
+                    .enumerate()
+                    .find_map(|(i, (value, parsed))| {
+                        find_dropped_value(value, &parsed.item).map(|p| format!("[{}]{}", i, p))
+                    })
+            } else {
+                Some(String::new())
+            }
+        }
+        _ => None,
+    }
+}
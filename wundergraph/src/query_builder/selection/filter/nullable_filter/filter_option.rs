@@ -12,7 +12,7 @@ use diesel::sql_types::{Bool, SingleValue};
 use diesel::{AppearsOnTable, Column};
 use indexmap::IndexMap;
 use juniper::meta::Argument;
-use juniper::{FromInputValue, InputValue, LookAheadValue, Registry};
+use juniper::{FromInputValue, InputValue, LookAheadValue, Registry, ToInputValue};
 
 use super::IsNull;
 
@@ -112,7 +112,10 @@ where
         }
     }
 
-    fn to_inner_input_value(&self, _v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {}
+    fn to_inner_input_value(&self, v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
+        v.insert("is_null", self.is_null.to_input_value());
+        self.additional.to_inner_input_value(v);
+    }
 
     fn register_fields<'r>(
         _info: &NameBuilder<Self>,
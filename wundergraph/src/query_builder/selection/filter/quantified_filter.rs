@@ -0,0 +1,229 @@
+use crate::diesel_ext::BoxableFilter;
+use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::filter::collector::{AndCollector, FilterCollector};
+use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::scalar::WundergraphScalarValue;
+use diesel::associations::HasTable;
+use diesel::backend::Backend;
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::sql_types::Bool;
+use diesel::{Column, QueryResult, QuerySource};
+use indexmap::IndexMap;
+use juniper::meta::Argument;
+use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, ToInputValue};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone, Copy)]
+enum Quantifier {
+    Some,
+    Every,
+    None,
+}
+
+/// Checks the rows of a related table referencing the current row
+///
+/// Depending on the quantifier this compiles down to
+/// * `EXISTS (SELECT 1 FROM other WHERE other.fk = pk AND filter)`
+/// * `NOT EXISTS (SELECT 1 FROM other WHERE other.fk = pk AND NOT COALESCE(filter, 1 = 0))`
+/// * `NOT EXISTS (SELECT 1 FROM other WHERE other.fk = pk AND filter)`
+#[derive(Debug)]
+pub struct RelatedExists<C, C2, F> {
+    quantifier: Quantifier,
+    filter: Option<F>,
+    p: PhantomData<(C, C2)>,
+}
+
+impl<C, C2, F> RelatedExists<C, C2, F> {
+    fn new(quantifier: Quantifier, filter: Option<F>) -> Self {
+        Self {
+            quantifier,
+            filter,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<C, C2, F> Expression for RelatedExists<C, C2, F> {
+    type SqlType = Bool;
+}
+
+impl<C, C2, F> NonAggregate for RelatedExists<C, C2, F> {}
+
+impl<C, C2, F, QS> AppearsOnTable<QS> for RelatedExists<C, C2, F> where C: AppearsOnTable<QS> {}
+
+impl<C, C2, F, QS> SelectableExpression<QS> for RelatedExists<C, C2, F> where
+    C: SelectableExpression<QS>
+{
+}
+
+impl<C, C2, F> QueryId for RelatedExists<C, C2, F> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<C, C2, F, DB> QueryFragment<DB> for RelatedExists<C, C2, F>
+where
+    DB: Backend,
+    C: QueryFragment<DB> + Default,
+    C2: Column + QueryFragment<DB> + Default,
+    C2::Table: HasTable<Table = C2::Table>,
+    <C2::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    F: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, DB>) -> QueryResult<()> {
+        if let Quantifier::Some = self.quantifier {
+            pass.push_sql("EXISTS (SELECT 1 FROM ");
+        } else {
+            pass.push_sql("NOT EXISTS (SELECT 1 FROM ");
+        }
+        C2::Table::table().from_clause().walk_ast(pass.reborrow())?;
+        pass.push_sql(" WHERE ");
+        C2::default().walk_ast(pass.reborrow())?;
+        pass.push_sql(" = ");
+        C::default().walk_ast(pass.reborrow())?;
+        if let Some(ref filter) = self.filter {
+            if let Quantifier::Every = self.quantifier {
+                // Rows where the filter evaluates to null do not match
+                pass.push_sql(" AND NOT COALESCE(");
+                filter.walk_ast(pass.reborrow())?;
+                pass.push_sql(", 1 = 0)");
+            } else {
+                pass.push_sql(" AND (");
+                filter.walk_ast(pass.reborrow())?;
+                pass.push_sql(")");
+            }
+        }
+        pass.push_sql(")");
+        Ok(())
+    }
+}
+
+/// Additional filter operations for `HasMany` relations
+///
+/// `some`, `every` and `none` check if at least one, all or
+/// no related entities match the given filter
+#[derive(Debug)]
+pub struct QuantifiedFilter<C, I, C2> {
+    some: Option<I>,
+    every: Option<I>,
+    none: Option<I>,
+    p: PhantomData<(C, C2)>,
+}
+
+impl<C, I, C2> Clone for QuantifiedFilter<C, I, C2>
+where
+    I: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            some: self.some.clone(),
+            every: self.every.clone(),
+            none: self.none.clone(),
+            p: PhantomData,
+        }
+    }
+}
+
+impl<C, I, C2> Nameable for QuantifiedFilter<C, I, C2> {
+    fn name() -> String {
+        String::from("quantified")
+    }
+}
+
+impl<C, I, C2, DB> BuildFilter<DB> for QuantifiedFilter<C, I, C2>
+where
+    DB: Backend + 'static,
+    C: Column + AppearsOnTable<C::Table> + NonAggregate + QueryFragment<DB> + Default + 'static,
+    C::Table: 'static,
+    C2: Column + QueryFragment<DB> + Default + 'static,
+    C2::Table: HasTable<Table = C2::Table>,
+    <C2::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    I: BuildFilter<DB>,
+    I::Ret: QueryFragment<DB> + 'static,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let exists = |quantifier, filter| {
+            Box::new(RelatedExists::<C, C2, _>::new(quantifier, filter)) as Self::Ret
+        };
+        let mut and = AndCollector::default();
+        and.append_filter(self.some.map(|f| exists(Quantifier::Some, f.into_filter())));
+        // `every` without any condition is always true
+        and.append_filter(
+            self.every
+                .and_then(BuildFilter::into_filter)
+                .map(|f| exists(Quantifier::Every, Some(f))),
+        );
+        and.append_filter(self.none.map(|f| exists(Quantifier::None, f.into_filter())));
+        and.into_filter()
+    }
+}
+
+impl<C, I, C2> InnerFilter for QuantifiedFilter<C, I, C2>
+where
+    I: GraphQLType<WundergraphScalarValue, TypeInfo = NameBuilder<I>>
+        + FromInputValue<WundergraphScalarValue>
+        + ToInputValue<WundergraphScalarValue>
+        + FromLookAheadValue
+        + Nameable,
+{
+    type Context = ();
+
+    const FIELD_COUNT: usize = 3;
+
+    fn from_inner_input_value(
+        obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
+    ) -> Option<Self> {
+        let some = obj.get("some").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        let every = obj.get("every").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        let none = obj.get("none").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        Some(Self {
+            some,
+            every,
+            none,
+            p: PhantomData,
+        })
+    }
+
+    fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
+        let get = |name: &str| {
+            obj.iter()
+                .find(|o| o.0 == name)
+                .and_then(|o| I::from_look_ahead(&o.1))
+        };
+        Self {
+            some: get("some"),
+            every: get("every"),
+            none: get("none"),
+            p: PhantomData,
+        }
+    }
+
+    fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
+        map.insert("some", self.some.to_input_value());
+        map.insert("every", self.every.to_input_value());
+        map.insert("none", self.none.to_input_value());
+    }
+
+    fn register_fields<'r>(
+        _info: &NameBuilder<Self>,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+        let some = registry.arg_with_default::<Option<I>>("some", &None, &NameBuilder::default());
+        let every = registry.arg_with_default::<Option<I>>("every", &None, &NameBuilder::default());
+        let none = registry.arg_with_default::<Option<I>>("none", &None, &NameBuilder::default());
+        vec![some, every, none]
+    }
+}
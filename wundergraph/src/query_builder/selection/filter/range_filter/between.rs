@@ -0,0 +1,173 @@
+use crate::diesel_ext::BoxableFilter;
+use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::expression::{operators, AsExpression, NonAggregate};
+use diesel::query_builder::QueryFragment;
+use diesel::sql_types::Bool;
+use diesel::{AppearsOnTable, BoolExpressionMethods, Column, ExpressionMethods};
+use indexmap::IndexMap;
+use juniper::meta::MetaType;
+use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, ToInputValue};
+use std::marker::PhantomData;
+
+/// A filter operation checking if a column value lies in a given range
+///
+/// If `inclusive` is set (the default) this compiles down to
+/// `column BETWEEN from AND to`, otherwise to `column > from AND column < to`
+#[derive(Debug)]
+pub struct Between<T, C> {
+    from: T,
+    to: T,
+    inclusive: bool,
+    p: PhantomData<C>,
+}
+
+impl<T, C> Clone for Between<T, C>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            from: self.from.clone(),
+            to: self.to.clone(),
+            inclusive: self.inclusive,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<T, C> Nameable for Between<T, C>
+where
+    T: Nameable,
+{
+    fn name() -> String {
+        format!("Between_{}_", T::name())
+    }
+}
+
+impl<T, C> FromInputValue<WundergraphScalarValue> for Between<T, C>
+where
+    T: FromInputValue<WundergraphScalarValue>,
+{
+    fn from_input_value(v: &InputValue<WundergraphScalarValue>) -> Option<Self> {
+        let obj = v.to_object_value()?;
+        let from = obj.get("from").and_then(|v| T::from_input_value(*v))?;
+        let to = obj.get("to").and_then(|v| T::from_input_value(*v))?;
+        let inclusive = match obj.get("inclusive") {
+            Some(v) => Option::<bool>::from_input_value(*v)?.unwrap_or(true),
+            None => true,
+        };
+        Some(Self {
+            from,
+            to,
+            inclusive,
+            p: PhantomData,
+        })
+    }
+}
+
+impl<T, C> FromLookAheadValue for Between<T, C>
+where
+    T: FromLookAheadValue,
+{
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Object(ref obj) = *v {
+            let from = obj
+                .iter()
+                .find(|o| o.0 == "from")
+                .and_then(|o| T::from_look_ahead(&o.1))?;
+            let to = obj
+                .iter()
+                .find(|o| o.0 == "to")
+                .and_then(|o| T::from_look_ahead(&o.1))?;
+            let inclusive = obj
+                .iter()
+                .find(|o| o.0 == "inclusive")
+                .and_then(|o| bool::from_look_ahead(&o.1))
+                .unwrap_or(true);
+            Some(Self {
+                from,
+                to,
+                inclusive,
+                p: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, C> ToInputValue<WundergraphScalarValue> for Between<T, C>
+where
+    T: ToInputValue<WundergraphScalarValue>,
+{
+    fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
+        let mut map = IndexMap::with_capacity(3);
+        map.insert("from", self.from.to_input_value());
+        map.insert("to", self.to.to_input_value());
+        map.insert("inclusive", self.inclusive.to_input_value());
+        InputValue::object(map)
+    }
+}
+
+impl<T, C> GraphQLType<WundergraphScalarValue> for Between<T, C>
+where
+    T: GraphQLType<WundergraphScalarValue, TypeInfo = ()>
+        + FromInputValue<WundergraphScalarValue>
+        + Nameable,
+{
+    type Context = ();
+    type TypeInfo = NameBuilder<Self>;
+
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        Some(info.name())
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let fields = &[
+            registry.arg::<T>("from", &()),
+            registry.arg::<T>("to", &()),
+            registry.arg_with_default::<bool>("inclusive", &true, &()),
+        ];
+        registry
+            .build_input_object_type::<Self>(info, fields)
+            .into_meta()
+    }
+}
+
+impl<C, T, DB> BuildFilter<DB> for Between<T, C>
+where
+    C: ExpressionMethods + NonAggregate + Column + QueryFragment<DB> + Default + 'static,
+    T: AsExpression<C::SqlType>,
+    T::Expression: NonAggregate + AppearsOnTable<C::Table> + QueryFragment<DB> + 'static,
+    DB: Backend + 'static,
+    C::Table: 'static,
+    operators::Between<C, operators::And<T::Expression, T::Expression>>:
+        AppearsOnTable<C::Table, SqlType = Bool>,
+    operators::And<operators::Gt<C, T::Expression>, operators::Lt<C, T::Expression>>:
+        AppearsOnTable<C::Table, SqlType = Bool>,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let Self {
+            from,
+            to,
+            inclusive,
+            ..
+        } = self;
+        if inclusive {
+            Some(Box::new(C::default().between(from, to)) as Box<_>)
+        } else {
+            Some(Box::new(C::default().gt(from).and(C::default().lt(to))) as Box<_>)
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::diesel_ext::BoxableFilter;
+use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::sql_types::Bool;
+use diesel::Column;
+use indexmap::IndexMap;
+use juniper::meta::Argument;
+use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, ToInputValue};
+
+mod between;
+use self::between::Between;
+
+/// Additional filter operations for types with a natural order
+/// like numbers or dates
+///
+/// Nullable columns of those types get the same operations, as
+/// `NullableFilter` includes the additional filter of the inner type
+#[derive(Debug)]
+pub struct RangeFilter<T, C> {
+    between: Option<Between<T, C>>,
+}
+
+impl<T, C> Clone for RangeFilter<T, C>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            between: self.between.clone(),
+        }
+    }
+}
+
+impl<T, C> Nameable for RangeFilter<T, C> {
+    fn name() -> String {
+        String::new()
+    }
+}
+
+impl<T, C, DB> BuildFilter<DB> for RangeFilter<T, C>
+where
+    DB: Backend,
+    C: Column,
+    Between<T, C>: BuildFilter<DB, Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>>,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        self.between.into_filter()
+    }
+}
+
+impl<T, C> InnerFilter for RangeFilter<T, C>
+where
+    T: GraphQLType<WundergraphScalarValue, TypeInfo = ()>
+        + FromInputValue<WundergraphScalarValue>
+        + ToInputValue<WundergraphScalarValue>
+        + FromLookAheadValue
+        + Nameable,
+{
+    type Context = ();
+
+    const FIELD_COUNT: usize = 1;
+
+    fn from_inner_input_value(
+        obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
+    ) -> Option<Self> {
+        let between = obj.get("between").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        Some(Self { between })
+    }
+
+    fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
+        let between = obj
+            .iter()
+            .find(|o| o.0 == "between")
+            .and_then(|o| Between::from_look_ahead(&o.1));
+        Self { between }
+    }
+
+    fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
+        map.insert("between", self.between.to_input_value());
+    }
+
+    fn register_fields<'r>(
+        _info: &NameBuilder<Self>,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+        let between = registry.arg_with_default::<Option<Between<T, C>>>(
+            "between",
+            &None,
+            &NameBuilder::default(),
+        );
+        vec![between]
+    }
+}
@@ -0,0 +1,289 @@
+use diesel::backend::Backend;
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+use diesel::sql_types::Bool;
+
+/// A case insensitive `LIKE` expression
+///
+/// This maps to `ILIKE` on PostgreSQL and to `lower(lhs) LIKE lower(rhs)`
+/// on SQLite, so matching ignores the case independently of the
+/// `case_sensitive_like` pragma
+#[derive(Debug, Clone, Copy)]
+pub struct CaseInsensitiveLike<L, R> {
+    left: L,
+    right: R,
+}
+
+impl<L, R> CaseInsensitiveLike<L, R> {
+    pub(super) fn new(left: L, right: R) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<L, R> Expression for CaseInsensitiveLike<L, R>
+where
+    L: Expression,
+    R: Expression,
+{
+    type SqlType = Bool;
+}
+
+impl<L, R> NonAggregate for CaseInsensitiveLike<L, R>
+where
+    L: NonAggregate,
+    R: NonAggregate,
+{
+}
+
+impl<L, R, QS> AppearsOnTable<QS> for CaseInsensitiveLike<L, R>
+where
+    L: AppearsOnTable<QS>,
+    R: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+impl<L, R, QS> SelectableExpression<QS> for CaseInsensitiveLike<L, R>
+where
+    L: SelectableExpression<QS>,
+    R: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl<L, R> QueryId for CaseInsensitiveLike<L, R> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+#[cfg(feature = "postgres")]
+impl<L, R> QueryFragment<diesel::pg::Pg> for CaseInsensitiveLike<L, R>
+where
+    L: QueryFragment<diesel::pg::Pg>,
+    R: QueryFragment<diesel::pg::Pg>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::pg::Pg>) -> QueryResult<()> {
+        self.left.walk_ast(pass.reborrow())?;
+        pass.push_sql(" ILIKE ");
+        self.right.walk_ast(pass.reborrow())?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<L, R> QueryFragment<diesel::sqlite::Sqlite> for CaseInsensitiveLike<L, R>
+where
+    L: QueryFragment<diesel::sqlite::Sqlite>,
+    R: QueryFragment<diesel::sqlite::Sqlite>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::sqlite::Sqlite>) -> QueryResult<()> {
+        pass.push_sql("lower(");
+        self.left.walk_ast(pass.reborrow())?;
+        pass.push_sql(") LIKE lower(");
+        self.right.walk_ast(pass.reborrow())?;
+        pass.push_sql(")");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<L, R> QueryFragment<diesel::mysql::Mysql> for CaseInsensitiveLike<L, R>
+where
+    L: QueryFragment<diesel::mysql::Mysql>,
+    R: QueryFragment<diesel::mysql::Mysql>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::mysql::Mysql>) -> QueryResult<()> {
+        pass.push_sql("lower(");
+        self.left.walk_ast(pass.reborrow())?;
+        pass.push_sql(") LIKE lower(");
+        self.right.walk_ast(pass.reborrow())?;
+        pass.push_sql(")");
+        Ok(())
+    }
+}
+
+/// A case insensitive equality check
+///
+/// Both sides are converted to lower case using the `lower` sql function
+/// before they are compared
+#[derive(Debug, Clone, Copy)]
+pub struct CaseInsensitiveEq<L, R> {
+    left: L,
+    right: R,
+}
+
+impl<L, R> CaseInsensitiveEq<L, R> {
+    pub(super) fn new(left: L, right: R) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<L, R> Expression for CaseInsensitiveEq<L, R>
+where
+    L: Expression,
+    R: Expression,
+{
+    type SqlType = Bool;
+}
+
+impl<L, R> NonAggregate for CaseInsensitiveEq<L, R>
+where
+    L: NonAggregate,
+    R: NonAggregate,
+{
+}
+
+impl<L, R, QS> AppearsOnTable<QS> for CaseInsensitiveEq<L, R>
+where
+    L: AppearsOnTable<QS>,
+    R: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+impl<L, R, QS> SelectableExpression<QS> for CaseInsensitiveEq<L, R>
+where
+    L: SelectableExpression<QS>,
+    R: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl<L, R> QueryId for CaseInsensitiveEq<L, R> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<L, R, DB> QueryFragment<DB> for CaseInsensitiveEq<L, R>
+where
+    DB: Backend,
+    L: QueryFragment<DB>,
+    R: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, DB>) -> QueryResult<()> {
+        pass.push_sql("lower(");
+        self.left.walk_ast(pass.reborrow())?;
+        pass.push_sql(") = lower(");
+        self.right.walk_ast(pass.reborrow())?;
+        pass.push_sql(")");
+        Ok(())
+    }
+}
+
+/// A regular expression match
+///
+/// This maps to `~` (or `~*` for case insensitive matches) on `Pg`
+/// and to the `REGEXP` operator on `Sqlite` and `Mysql`. Case insensitive
+/// matches are implemented on `Sqlite` by prefixing the pattern with `(?i)`.
+/// As `Mysql` matches case insensitive by default for most collations, the
+/// pattern is always prefixed with `(?i)` or `(?-i)` there
+#[derive(Debug, Clone, Copy)]
+pub struct RegexMatch<L, R> {
+    left: L,
+    right: R,
+    case_insensitive: bool,
+}
+
+impl<L, R> RegexMatch<L, R> {
+    pub(super) fn new(left: L, right: R, case_insensitive: bool) -> Self {
+        Self {
+            left,
+            right,
+            case_insensitive,
+        }
+    }
+}
+
+impl<L, R> Expression for RegexMatch<L, R>
+where
+    L: Expression,
+    R: Expression,
+{
+    type SqlType = Bool;
+}
+
+impl<L, R> NonAggregate for RegexMatch<L, R>
+where
+    L: NonAggregate,
+    R: NonAggregate,
+{
+}
+
+impl<L, R, QS> AppearsOnTable<QS> for RegexMatch<L, R>
+where
+    L: AppearsOnTable<QS>,
+    R: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+impl<L, R, QS> SelectableExpression<QS> for RegexMatch<L, R>
+where
+    L: SelectableExpression<QS>,
+    R: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl<L, R> QueryId for RegexMatch<L, R> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+#[cfg(feature = "postgres")]
+impl<L, R> QueryFragment<diesel::pg::Pg> for RegexMatch<L, R>
+where
+    L: QueryFragment<diesel::pg::Pg>,
+    R: QueryFragment<diesel::pg::Pg>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::pg::Pg>) -> QueryResult<()> {
+        self.left.walk_ast(pass.reborrow())?;
+        if self.case_insensitive {
+            pass.push_sql(" ~* ");
+        } else {
+            pass.push_sql(" ~ ");
+        }
+        self.right.walk_ast(pass.reborrow())?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<L, R> QueryFragment<diesel::sqlite::Sqlite> for RegexMatch<L, R>
+where
+    L: QueryFragment<diesel::sqlite::Sqlite>,
+    R: QueryFragment<diesel::sqlite::Sqlite>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::sqlite::Sqlite>) -> QueryResult<()> {
+        self.left.walk_ast(pass.reborrow())?;
+        if self.case_insensitive {
+            pass.push_sql(" REGEXP ('(?i)' || ");
+            self.right.walk_ast(pass.reborrow())?;
+            pass.push_sql(")");
+        } else {
+            pass.push_sql(" REGEXP ");
+            self.right.walk_ast(pass.reborrow())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<L, R> QueryFragment<diesel::mysql::Mysql> for RegexMatch<L, R>
+where
+    L: QueryFragment<diesel::mysql::Mysql>,
+    R: QueryFragment<diesel::mysql::Mysql>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::mysql::Mysql>) -> QueryResult<()> {
+        self.left.walk_ast(pass.reborrow())?;
+        if self.case_insensitive {
+            pass.push_sql(" REGEXP CONCAT('(?i)', ");
+        } else {
+            pass.push_sql(" REGEXP CONCAT('(?-i)', ");
+        }
+        self.right.walk_ast(pass.reborrow())?;
+        pass.push_sql(")");
+        Ok(())
+    }
+}
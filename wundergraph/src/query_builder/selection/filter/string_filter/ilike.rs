@@ -0,0 +1,54 @@
+use super::expression::CaseInsensitiveLike;
+use crate::diesel_ext::BoxableFilter;
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::expression::{AsExpression, NonAggregate};
+use diesel::query_builder::QueryFragment;
+use diesel::serialize::ToSql;
+use diesel::sql_types::{Bool, HasSqlType, Text};
+use diesel::{AppearsOnTable, Column};
+use juniper::{InputValue, ToInputValue};
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub struct ILike<C>(Option<String>, PhantomData<C>);
+
+impl<C> ILike<C> {
+    pub(super) fn new(v: Option<String>) -> Self {
+        Self(v, PhantomData)
+    }
+}
+
+impl<C> Clone for ILike<C> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<C, DB> BuildFilter<DB> for ILike<C>
+where
+    C: NonAggregate + Column + QueryFragment<DB> + Default + 'static,
+    String: AsExpression<C::SqlType>,
+    <String as AsExpression<C::SqlType>>::Expression:
+        NonAggregate + AppearsOnTable<C::Table> + QueryFragment<DB> + 'static,
+    DB: Backend + HasSqlType<Text> + 'static,
+    String: ToSql<Text, DB>,
+    C::Table: 'static,
+    CaseInsensitiveLike<C, <String as AsExpression<C::SqlType>>::Expression>:
+        AppearsOnTable<C::Table, SqlType = Bool> + QueryFragment<DB>,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let Self(filter, _) = self;
+        filter
+            .map(|v| Box::new(CaseInsensitiveLike::new(C::default(), v.as_expression())) as Box<_>)
+    }
+}
+
+impl<C> ToInputValue<WundergraphScalarValue> for ILike<C> {
+    fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
+        self.0.to_input_value()
+    }
+}
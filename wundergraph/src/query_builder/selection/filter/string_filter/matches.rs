@@ -0,0 +1,71 @@
+use super::expression::RegexMatch;
+use crate::diesel_ext::BoxableFilter;
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::expression::{AsExpression, NonAggregate};
+use diesel::query_builder::QueryFragment;
+use diesel::serialize::ToSql;
+use diesel::sql_types::{Bool, HasSqlType, Text};
+use diesel::{AppearsOnTable, Column};
+use juniper::{InputValue, ToInputValue};
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub struct Matches<C> {
+    pattern: Option<String>,
+    case_insensitive: bool,
+    p: PhantomData<C>,
+}
+
+impl<C> Matches<C> {
+    pub(super) fn new(pattern: Option<String>, case_insensitive: bool) -> Self {
+        Self {
+            pattern,
+            case_insensitive,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<C> Clone for Matches<C> {
+    fn clone(&self) -> Self {
+        Self {
+            pattern: self.pattern.clone(),
+            case_insensitive: self.case_insensitive,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<C, DB> BuildFilter<DB> for Matches<C>
+where
+    C: NonAggregate + Column + QueryFragment<DB> + Default + 'static,
+    String: AsExpression<C::SqlType>,
+    <String as AsExpression<C::SqlType>>::Expression:
+        NonAggregate + AppearsOnTable<C::Table> + QueryFragment<DB> + 'static,
+    DB: Backend + HasSqlType<Text> + 'static,
+    String: ToSql<Text, DB>,
+    C::Table: 'static,
+    RegexMatch<C, <String as AsExpression<C::SqlType>>::Expression>:
+        AppearsOnTable<C::Table, SqlType = Bool> + QueryFragment<DB>,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let case_insensitive = self.case_insensitive;
+        self.pattern.map(|v| {
+            Box::new(RegexMatch::new(
+                C::default(),
+                v.as_expression(),
+                case_insensitive,
+            )) as Box<_>
+        })
+    }
+}
+
+impl<C> ToInputValue<WundergraphScalarValue> for Matches<C> {
+    fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
+        self.pattern.to_input_value()
+    }
+}
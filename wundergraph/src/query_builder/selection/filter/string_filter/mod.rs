@@ -1,6 +1,7 @@
 use crate::diesel_ext::BoxableFilter;
 use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
 use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::filter::collector::{AndCollector, FilterCollector};
 use crate::query_builder::selection::filter::inner_filter::InnerFilter;
 use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
@@ -10,18 +11,53 @@ use indexmap::IndexMap;
 use juniper::meta::Argument;
 use juniper::{FromInputValue, InputValue, LookAheadValue, Registry, ToInputValue};
 
+mod eq_ignore_case;
+mod expression;
+mod ilike;
 mod like;
+// Sqlite only supports `REGEXP` with the function registered by the `regex` feature
+#[cfg_attr(all(feature = "sqlite", not(feature = "regex")), allow(dead_code))]
+mod matches;
+mod pattern;
+
+use self::eq_ignore_case::EqIgnoreCase;
+use self::ilike::ILike;
 use self::like::Like;
+use self::matches::Matches;
+use self::pattern::{PatternKind, PatternMatch};
+
+// `starts_with`, `ends_with` and `contains` are compiled to `LIKE`, so they
+// inherit the case sensitivity of `LIKE` of the used database
+const PATTERN_CASE_SENSITIVITY: &str = "The comparison is case sensitive on PostgreSQL, \
+     ignores the case of ASCII characters on SQLite and depends on the collation of the column on MySQL";
 
 #[derive(Debug)]
 pub struct StringFilter<C> {
     like: Like<C>,
+    ilike: ILike<C>,
+    eq_ignore_case: EqIgnoreCase<C>,
+    starts_with: PatternMatch<C>,
+    ends_with: PatternMatch<C>,
+    contains: PatternMatch<C>,
+    #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+    matches: Matches<C>,
+    #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+    imatches: Matches<C>,
 }
 
 impl<C> Clone for StringFilter<C> {
     fn clone(&self) -> Self {
         Self {
             like: self.like.clone(),
+            ilike: self.ilike.clone(),
+            eq_ignore_case: self.eq_ignore_case.clone(),
+            starts_with: self.starts_with.clone(),
+            ends_with: self.ends_with.clone(),
+            contains: self.contains.clone(),
+            #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+            matches: self.matches.clone(),
+            #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+            imatches: self.imatches.clone(),
         }
     }
 }
@@ -34,54 +70,187 @@ impl<C> Nameable for StringFilter<C> {
 
 impl<C, DB> BuildFilter<DB> for StringFilter<C>
 where
-    DB: Backend,
-    C: Column,
+    DB: Backend + 'static,
+    C: Column + 'static,
+    C::Table: 'static,
     Like<C>: BuildFilter<DB, Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>>,
+    ILike<C>: BuildFilter<DB, Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>>,
+    EqIgnoreCase<C>: BuildFilter<DB, Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>>,
+    PatternMatch<C>: BuildFilter<DB, Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>>,
+    Matches<C>: BuildFilter<DB, Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>>,
 {
     type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
 
     fn into_filter(self) -> Option<Self::Ret> {
-        self.like.into_filter()
+        let mut combinator = AndCollector::default();
+        combinator.append_filter(self.like);
+        combinator.append_filter(self.ilike);
+        combinator.append_filter(self.eq_ignore_case);
+        combinator.append_filter(self.starts_with);
+        combinator.append_filter(self.ends_with);
+        combinator.append_filter(self.contains);
+        #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+        {
+            combinator.append_filter(self.matches);
+            combinator.append_filter(self.imatches);
+        }
+        combinator.into_filter()
     }
 }
 
+fn string_from_input_value(
+    obj: &IndexMap<&str, &InputValue<WundergraphScalarValue>>,
+    name: &str,
+) -> Option<Option<String>> {
+    obj.get(name).map_or_else(
+        || {
+            let v: &InputValue<WundergraphScalarValue> = &InputValue::Null;
+            Option::from_input_value(v)
+        },
+        |v| Option::from_input_value(*v),
+    )
+}
+
+fn string_from_look_ahead(
+    obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    name: &str,
+) -> Option<String> {
+    obj.iter()
+        .find(|o| o.0 == name)
+        .and_then(|o| String::from_look_ahead(&o.1))
+}
+
 impl<C> InnerFilter for StringFilter<C> {
     type Context = ();
 
-    const FIELD_COUNT: usize = 1;
+    #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+    const FIELD_COUNT: usize = 8;
+    #[cfg(all(feature = "sqlite", not(feature = "regex")))]
+    const FIELD_COUNT: usize = 6;
 
+    #[allow(clippy::similar_names)]
     fn from_inner_input_value(
         obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
     ) -> Option<Self> {
-        let like = Like::new(obj.get("like").map_or_else(
-            || {
-                let v: &InputValue<WundergraphScalarValue> = &InputValue::Null;
-                Option::from_input_value(v)
-            },
-            |v| Option::from_input_value(*v),
-        )?);
-        Some(Self { like })
+        let like = Like::new(string_from_input_value(&obj, "like")?);
+        let ilike = ILike::new(string_from_input_value(&obj, "ilike")?);
+        let eq_ignore_case = EqIgnoreCase::new(string_from_input_value(&obj, "eq_ignore_case")?);
+        let starts_with = PatternMatch::new(
+            string_from_input_value(&obj, "starts_with")?,
+            PatternKind::StartsWith,
+        );
+        let ends_with = PatternMatch::new(
+            string_from_input_value(&obj, "ends_with")?,
+            PatternKind::EndsWith,
+        );
+        let contains = PatternMatch::new(
+            string_from_input_value(&obj, "contains")?,
+            PatternKind::Contains,
+        );
+        #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+        let matches = Matches::new(string_from_input_value(&obj, "matches")?, false);
+        #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+        let imatches = Matches::new(string_from_input_value(&obj, "imatches")?, true);
+        Some(Self {
+            like,
+            ilike,
+            eq_ignore_case,
+            starts_with,
+            ends_with,
+            contains,
+            #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+            matches,
+            #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+            imatches,
+        })
     }
 
     fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
-        let like = obj
-            .iter()
-            .find(|o| o.0 == "like")
-            .and_then(|o| String::from_look_ahead(&o.1));
         Self {
-            like: Like::new(like),
+            like: Like::new(string_from_look_ahead(obj, "like")),
+            ilike: ILike::new(string_from_look_ahead(obj, "ilike")),
+            eq_ignore_case: EqIgnoreCase::new(string_from_look_ahead(obj, "eq_ignore_case")),
+            starts_with: PatternMatch::new(
+                string_from_look_ahead(obj, "starts_with"),
+                PatternKind::StartsWith,
+            ),
+            ends_with: PatternMatch::new(
+                string_from_look_ahead(obj, "ends_with"),
+                PatternKind::EndsWith,
+            ),
+            contains: PatternMatch::new(
+                string_from_look_ahead(obj, "contains"),
+                PatternKind::Contains,
+            ),
+            #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+            matches: Matches::new(string_from_look_ahead(obj, "matches"), false),
+            #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+            imatches: Matches::new(string_from_look_ahead(obj, "imatches"), true),
         }
     }
 
     fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
         map.insert("like", self.like.to_input_value());
+        map.insert("ilike", self.ilike.to_input_value());
+        map.insert("eq_ignore_case", self.eq_ignore_case.to_input_value());
+        map.insert("starts_with", self.starts_with.to_input_value());
+        map.insert("ends_with", self.ends_with.to_input_value());
+        map.insert("contains", self.contains.to_input_value());
+        #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+        {
+            map.insert("matches", self.matches.to_input_value());
+            map.insert("imatches", self.imatches.to_input_value());
+        }
     }
 
+    #[allow(clippy::similar_names)]
     fn register_fields<'r>(
         _info: &NameBuilder<Self>,
         registry: &mut Registry<'r, WundergraphScalarValue>,
     ) -> Vec<Argument<'r, WundergraphScalarValue>> {
         let like = registry.arg_with_default::<Option<String>>("like", &None, &Default::default());
-        vec![like]
+        let ilike =
+            registry.arg_with_default::<Option<String>>("ilike", &None, &Default::default());
+        let eq_ignore_case = registry.arg_with_default::<Option<String>>(
+            "eq_ignore_case",
+            &None,
+            &Default::default(),
+        );
+        let starts_with = registry
+            .arg_with_default::<Option<String>>("starts_with", &None, &Default::default())
+            .description(&format!(
+                "Matches values starting with the given string. {}",
+                PATTERN_CASE_SENSITIVITY
+            ));
+        let ends_with = registry
+            .arg_with_default::<Option<String>>("ends_with", &None, &Default::default())
+            .description(&format!(
+                "Matches values ending with the given string. {}",
+                PATTERN_CASE_SENSITIVITY
+            ));
+        let contains = registry
+            .arg_with_default::<Option<String>>("contains", &None, &Default::default())
+            .description(&format!(
+                "Matches values containing the given string. {}",
+                PATTERN_CASE_SENSITIVITY
+            ));
+        #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+        let matches =
+            registry.arg_with_default::<Option<String>>("matches", &None, &Default::default());
+        #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+        let imatches =
+            registry.arg_with_default::<Option<String>>("imatches", &None, &Default::default());
+        vec![
+            like,
+            ilike,
+            eq_ignore_case,
+            starts_with,
+            ends_with,
+            contains,
+            #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+            matches,
+            #[cfg(any(feature = "regex", not(feature = "sqlite")))]
+            imatches,
+        ]
     }
 }
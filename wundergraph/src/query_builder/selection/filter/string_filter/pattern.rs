@@ -0,0 +1,116 @@
+use crate::diesel_ext::BoxableFilter;
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::expression::{operators, AsExpression, NonAggregate};
+use diesel::query_builder::QueryFragment;
+use diesel::serialize::ToSql;
+use diesel::sql_types::{Bool, HasSqlType, Text, VarChar};
+use diesel::{AppearsOnTable, Column, EscapeExpressionMethods, TextExpressionMethods};
+use juniper::{InputValue, ToInputValue};
+use std::marker::PhantomData;
+
+/// The character used to escape wildcards in the patterns of a backend
+pub trait PatternEscape {
+    const ESCAPE_CHAR: char = '\\';
+}
+
+#[cfg(feature = "postgres")]
+impl PatternEscape for diesel::pg::Pg {}
+
+#[cfg(feature = "sqlite")]
+impl PatternEscape for diesel::sqlite::Sqlite {}
+
+// Mysql interprets a backslash inside of string literals as escape
+// sequence, so `ESCAPE '\'` would not be a valid clause there
+#[cfg(feature = "mysql")]
+impl PatternEscape for diesel::mysql::Mysql {
+    const ESCAPE_CHAR: char = '!';
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) enum PatternKind {
+    StartsWith,
+    EndsWith,
+    Contains,
+}
+
+/// A `LIKE` based filter matching a plain string at a given position
+///
+/// In contrast to `Like` the given value is not interpreted as pattern,
+/// all wildcard characters are escaped before building the query.
+/// As `LIKE` is case insensitive for ASCII characters on SQLite, so is
+/// this filter, while it is case sensitive on PostgreSQL
+#[derive(Debug)]
+pub struct PatternMatch<C> {
+    value: Option<String>,
+    kind: PatternKind,
+    p: PhantomData<C>,
+}
+
+impl<C> PatternMatch<C> {
+    pub(super) fn new(value: Option<String>, kind: PatternKind) -> Self {
+        Self {
+            value,
+            kind,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<C> Clone for PatternMatch<C> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            kind: self.kind,
+            p: PhantomData,
+        }
+    }
+}
+
+fn escape_pattern(value: &str, escape_char: char) -> String {
+    let mut ret = String::with_capacity(value.len() + 2);
+    for c in value.chars() {
+        if c == '%' || c == '_' || c == escape_char {
+            ret.push(escape_char);
+        }
+        ret.push(c);
+    }
+    ret
+}
+
+impl<C, DB> BuildFilter<DB> for PatternMatch<C>
+where
+    C: TextExpressionMethods + NonAggregate + Column + QueryFragment<DB> + Default + 'static,
+    String: AsExpression<C::SqlType>,
+    <String as AsExpression<C::SqlType>>::Expression:
+        NonAggregate + AppearsOnTable<C::Table> + QueryFragment<DB> + 'static,
+    DB: Backend + PatternEscape + HasSqlType<Text> + HasSqlType<VarChar> + 'static,
+    String: ToSql<Text, DB> + ToSql<VarChar, DB>,
+    C::Table: 'static,
+    operators::Escape<
+        operators::Like<C, <String as AsExpression<C::SqlType>>::Expression>,
+        <String as AsExpression<VarChar>>::Expression,
+    >: AppearsOnTable<C::Table, SqlType = Bool>,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let kind = self.kind;
+        self.value.map(|v| {
+            let v = escape_pattern(&v, DB::ESCAPE_CHAR);
+            let pattern = match kind {
+                PatternKind::StartsWith => format!("{}%", v),
+                PatternKind::EndsWith => format!("%{}", v),
+                PatternKind::Contains => format!("%{}%", v),
+            };
+            Box::new(C::default().like(pattern).escape(DB::ESCAPE_CHAR)) as Box<_>
+        })
+    }
+}
+
+impl<C> ToInputValue<WundergraphScalarValue> for PatternMatch<C> {
+    fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
+        self.value.to_input_value()
+    }
+}
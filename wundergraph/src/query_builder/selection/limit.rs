@@ -0,0 +1,69 @@
+//! Default and maximal limits for top level queries
+
+use crate::context::WundergraphContext;
+use crate::error::{Result, WundergraphError};
+use crate::query_builder::selection::offset::ApplyOffset;
+use crate::query_builder::selection::LoadingHandler;
+use diesel::backend::Backend;
+
+/// The limits applied to top level queries of an entity
+///
+/// The `DEFAULT_LIMIT` and `MAX_LIMIT` constants of a
+/// [`LoadingHandler`](../trait.LoadingHandler.html) take precedence over
+/// the values returned by the context
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LimitPolicy {
+    default_limit: Option<i64>,
+    max_limit: Option<i64>,
+}
+
+impl LimitPolicy {
+    /// Construct a new policy from the given limits
+    pub fn new(default_limit: Option<i64>, max_limit: Option<i64>) -> Self {
+        Self {
+            default_limit,
+            max_limit,
+        }
+    }
+
+    /// Get the policy for the entity `L`
+    pub fn for_entity<L, DB, Ctx>(ctx: &Ctx) -> Self
+    where
+        DB: Backend + ApplyOffset + 'static,
+        L: LoadingHandler<DB, Ctx>,
+        Ctx: WundergraphContext,
+    {
+        Self::new(
+            L::DEFAULT_LIMIT.or_else(|| ctx.default_limit()),
+            L::MAX_LIMIT.or_else(|| ctx.max_limit()),
+        )
+    }
+
+    /// Check a requested limit against the maximal limit
+    ///
+    /// `name` is the name of the argument containing the limit
+    pub fn check(&self, name: &str, limit: i64) -> Result<i64> {
+        match self.max_limit {
+            Some(max) if limit > max => Err(WundergraphError::LimitExceeded {
+                name: name.to_owned(),
+                limit,
+                max,
+            }),
+            _ => Ok(limit),
+        }
+    }
+
+    /// Compute the limit to apply to a query
+    ///
+    /// Returns the checked requested limit if given, otherwise the
+    /// default limit capped to the maximal limit
+    pub fn limit(&self, name: &str, requested: Option<i64>) -> Result<Option<i64>> {
+        match requested {
+            Some(limit) => self.check(name, limit).map(Some),
+            None => Ok(match (self.default_limit, self.max_limit) {
+                (Some(default), Some(max)) => Some(default.min(max)),
+                (default, max) => default.or(max),
+            }),
+        }
+    }
+}
@@ -20,36 +20,45 @@
 //! * For each foreign key field [`WundergraphBelongsTo`](fields/trait.WundergraphBelongsTo.html)
 //! * [`BuildFilterHelper`](filter/trait.BuildFilterHelper.html) if you use your
 //!   entity somewhere as part of a filter
+//! * [`FullTextSearch`](search/trait.FullTextSearch.html) for the underlying
+//!   table if you enable full text search on the sqlite backend
 //!
 //! See the documentation of the corresponding traits on details about the
 //! actual implementation
 use crate::context::WundergraphContext;
+use crate::diesel_ext::BoxableFilter;
 use crate::error::{Result, WundergraphError};
 use crate::helper::tuple::IsPrimaryKeyIndex;
 use crate::helper::{PrimaryKeyArgument, UnRef};
 use crate::juniper_ext::FromLookAheadValue;
-use crate::query_builder::selection::order::BuildOrder;
+use crate::query_builder::selection::order::{BuildOrder, Nulls, Order, OrderClauses, RELEVANCE};
 use crate::query_builder::selection::select::BuildSelect;
 use crate::scalar::WundergraphScalarValue;
 use diesel::associations::HasTable;
 use diesel::backend::Backend;
+use diesel::deserialize::FromSql;
 use diesel::dsl::SqlTypeOf;
 use diesel::expression::NonAggregate;
 use diesel::query_builder::{BoxedSelectStatement, QueryFragment};
 use diesel::query_dsl::methods::BoxedDsl;
 use diesel::query_dsl::methods::FilterDsl;
-use diesel::query_dsl::methods::{LimitDsl, SelectDsl};
-use diesel::sql_types::HasSqlType;
+use diesel::query_dsl::methods::{LimitDsl, OrFilterDsl, SelectDsl};
+use diesel::sql_types::{BigInt, Bool, HasSqlType};
 use diesel::BoxableExpression;
 use diesel::EqAll;
 use diesel::Identifiable;
 use diesel::QuerySource;
-use diesel::{AppearsOnTable, Connection, QueryDsl, Table};
+use diesel::{AppearsOnTable, Connection, QueryDsl, Queryable, Table};
 use juniper::LookAheadValue;
-use juniper::{Executor, LookAheadArgument, LookAheadSelection, Selection};
+use juniper::{meta, Executor, LookAheadArgument, LookAheadSelection, Registry, Selection};
+use std::collections::HashMap;
+use std::hash::Hash;
 
+pub mod aggregate;
+pub mod connection;
 pub mod fields;
 pub mod filter;
+pub mod limit;
 #[doc(hidden)]
 pub mod offset;
 #[doc(hidden)]
@@ -58,13 +67,24 @@ pub(crate) mod query_modifier;
 #[doc(hidden)]
 pub mod query_resolver;
 #[doc(hidden)]
+pub mod reference_order;
+#[doc(hidden)]
+pub mod search;
+#[doc(hidden)]
 pub mod select;
+#[doc(hidden)]
+pub mod unique;
+#[doc(hidden)]
+pub mod window;
 
+use self::aggregate::{AggregateFunction, AggregateQuery, AggregateRow, GroupKey, GroupKeySqlType};
 use self::fields::WundergraphFieldList;
 use self::filter::build_filter::BuildFilter;
 use self::filter::inner_filter::InnerFilter;
 use self::filter::Filter;
+use self::limit::LimitPolicy;
 use self::offset::ApplyOffset;
+use self::query_resolver::get_sub_field;
 
 #[doc(inline)]
 pub use self::query_resolver::SqlTypeOfPlaceholder;
@@ -184,6 +204,147 @@ where
     const TYPE_NAME: &'static str;
     /// The graphql description of the current type
     const TYPE_DESCRIPTION: Option<&'static str> = None;
+    /// Order applied if a query does not provide an `order` argument
+    ///
+    /// Contains pairs of order column names and directions. Set via
+    /// `#[wundergraph(default_order = "field asc")]` by `#[derive(WundergraphEntity)]`
+    const DEFAULT_ORDER: &'static [(&'static str, Order)] = &[];
+
+    /// Limit applied to top level queries without a `limit` argument
+    ///
+    /// Set via `#[wundergraph(default_limit = 50)]` by
+    /// `#[derive(WundergraphEntity)]`. Takes precedence over
+    /// [`WundergraphContext::default_limit`](../../trait.WundergraphContext.html#method.default_limit)
+    const DEFAULT_LIMIT: Option<i64> = None;
+
+    /// Largest limit a top level query may request
+    ///
+    /// Set via `#[wundergraph(max_limit = 500)]` by
+    /// `#[derive(WundergraphEntity)]`. Takes precedence over
+    /// [`WundergraphContext::max_limit`](../../trait.WundergraphContext.html#method.max_limit)
+    const MAX_LIMIT: Option<i64> = None;
+
+    /// Whether filter arguments are checked for values that could not be
+    /// interpreted
+    ///
+    /// If set, such values are reported as
+    /// `WundergraphError::InvalidFilterArgument` instead of being ignored.
+    /// The check compares the argument with the input value written by
+    /// `InnerFilter::to_inner_input_value`, so all filters of this entity
+    /// need to write back each of their fields. Set via
+    /// `#[wundergraph(strict_filter)]` by `#[derive(WundergraphEntity)]`
+    const STRICT_FILTER: bool = false;
+
+    /// Graphql names of fields with a unique value for each entity
+    ///
+    /// `query_object!` generates a `{Entity}By{Field}` field loading a
+    /// single entity for each of those fields, see
+    /// [`unique`](unique/index.html). Set via `#[wundergraph(unique)]`
+    /// by `#[derive(WundergraphEntity)]`
+    const UNIQUE_FIELDS: &'static [&'static str] = &[];
+
+    /// Whether this entity supports full text search
+    ///
+    /// Entities supporting full text search accept a `search` argument
+    /// next to the `filter` argument and could be ordered by `RELEVANCE`.
+    /// Set via `#[wundergraph(full_text_search = "fts_table")]` by
+    /// `#[derive(WundergraphEntity)]`, see [`search`](search/index.html)
+    const FULL_TEXT_SEARCH: bool = false;
+
+    /// Additional order columns referring to related entities
+    ///
+    /// `#[derive(WundergraphEntity)]` returns `{field}_{related_field}`
+    /// for each column of each entity referenced by a `HasOne` field, see
+    /// [`ReferenceOrder`](reference_order/struct.ReferenceOrder.html), and
+    /// `{field}_COUNT` for each `HasMany` field, see
+    /// [`CountOrder`](reference_order/struct.CountOrder.html)
+    fn reference_order_columns() -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Build an order clause for a column returned by `reference_order_columns`
+    ///
+    /// Returns `None` if the given column is unknown
+    fn build_reference_order(
+        _column: &str,
+        _order: Order,
+        _nulls: Option<Nulls>,
+    ) -> Option<OrderClauses<Self::Table, DB>> {
+        None
+    }
+
+    /// Build a filter matching all entities found by a full text search
+    /// for `query`
+    ///
+    /// Only called if `FULL_TEXT_SEARCH` is set
+    fn build_full_text_search_filter(
+        _query: &str,
+    ) -> Option<Box<dyn BoxableFilter<Self::Table, DB, SqlType = Bool>>> {
+        None
+    }
+
+    /// Build an order clause ordering by the relevance of each entity
+    /// for a full text search for `query`
+    ///
+    /// Only called if `FULL_TEXT_SEARCH` is set
+    fn build_relevance_order(
+        _query: &str,
+        _order: Order,
+        _nulls: Option<Nulls>,
+    ) -> Option<OrderClauses<Self::Table, DB>> {
+        None
+    }
+
+    /// Register the fields of the object returned for `function` by
+    /// the `{name}Aggregate` field
+    ///
+    /// `#[derive(WundergraphEntity)]` registers a field for each field with
+    /// a numeric type and, for `min` and `max`, for each field with a
+    /// temporal type, see
+    /// [`AggregateColumn`](aggregate/struct.AggregateColumn.html)
+    fn aggregate_fields<'r>(
+        _function: AggregateFunction,
+        _registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<meta::Field<'r, WundergraphScalarValue>> {
+        Vec::new()
+    }
+
+    /// Load the number of entities and the `requested` aggregates of each
+    /// group selected by `query`
+    ///
+    /// `requested` contains pairs of an aggregate function and the name of
+    /// a field returned by `aggregate_fields`, all of them are loaded by a
+    /// single query. Returns one row per group, in the order of the groups
+    fn load_aggregate_values<'a>(
+        requested: &[(AggregateFunction, &str)],
+        query: AggregateQuery<'a, Self, DB, Ctx>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<Vec<AggregateRow<GroupKey<Self, DB, Ctx>>>>
+    where
+        Ctx: WundergraphContext,
+        Ctx::Connection: Connection<Backend = DB>,
+        DB: HasSqlType<(GroupKeySqlType<Self, DB, Ctx>, BigInt)>,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+        (GroupKey<Self, DB, Ctx>, i64): Queryable<(GroupKeySqlType<Self, DB, Ctx>, BigInt), DB>,
+    {
+        if let Some((_, field)) = requested.first() {
+            return Err(WundergraphError::UnknownDatabaseField {
+                name: (*field).to_owned(),
+            });
+        }
+        let rows = query.load(
+            diesel::dsl::count_star(),
+            executor.context().get_connection(),
+        )?;
+        Ok(rows
+            .into_iter()
+            .map(|(key, count)| AggregateRow {
+                key,
+                count,
+                values: Vec::new(),
+            })
+            .collect())
+    }
 
     /// Main entry point to loading something from the database
     ///
@@ -272,6 +433,613 @@ where
         Ok(res.into_iter().next())
     }
 
+    /// Load the entities with the primary keys given by the `primaryKeys`
+    /// argument
+    ///
+    /// All entities are loaded by a single query. Returns one value for each
+    /// requested key in the same order, `null` if there is no entity with
+    /// that key. The number of keys is bounded by the maximal limit of the
+    /// entity, see [`LimitPolicy`](limit/struct.LimitPolicy.html). The
+    /// default implementation restricts `query` via `filter_by_primary_keys`
+    /// and applies the `QueryModifier`
+    fn load_by_primary_keys<'a>(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        query: BoxedQuery<'a, Self, DB, Ctx>,
+    ) -> Result<Vec<juniper::Value<WundergraphScalarValue>>>
+    where
+        Self: 'static,
+        Self::Table: 'static,
+        &'static Self: Identifiable,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        <&'static Self as Identifiable>::Id: UnRef<'static>,
+        <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed: Hash + Eq + Clone,
+        <Self::Table as Table>::PrimaryKey:
+            EqAll<<<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed> + Default,
+        <Self::Table as Table>::PrimaryKey: QueryFragment<DB>,
+        <<Self::Table as Table>::PrimaryKey as EqAll<
+            <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+        >>::Output: AppearsOnTable<Self::Table> + NonAggregate + QueryFragment<DB>,
+        PrimaryKeyArgument<'static, Self::Table, (), <&'static Self as Identifiable>::Id>:
+            FromLookAheadValue,
+        DB: HasSqlType<(
+            SqlTypeOf<<Self::Table as Table>::PrimaryKey>,
+            SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+        )>,
+        (
+            <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+            <Self::FieldList as WundergraphFieldList<
+                DB,
+                Self::PrimaryKeyIndex,
+                Self::Table,
+                Ctx,
+            >>::PlaceHolder,
+        ): Queryable<
+            (
+                SqlTypeOf<<Self::Table as Table>::PrimaryKey>,
+                SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+            ),
+            DB,
+        >,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    {
+        use diesel::RunQueryDsl;
+        use juniper::LookAheadMethods;
+
+        let keys = match select.argument("primaryKeys").map(LookAheadArgument::value) {
+            Some(LookAheadValue::List(keys)) => keys
+                .iter()
+                .map(|key| {
+                    PrimaryKeyArgument::<
+                        Self::Table,
+                        _,
+                        <&'static Self as Identifiable>::Id,
+                    >::from_look_ahead(key)
+                    .map(|key| key.values)
+                    .ok_or(WundergraphError::NoPrimaryKeyArgumentFound)
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => return Err(WundergraphError::NoPrimaryKeyArgumentFound),
+        };
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+        let ctx = executor.context();
+        // Each key adds a condition to the query, so their number is
+        // bounded like the number of returned entities
+        LimitPolicy::for_entity::<Self, DB, Ctx>(ctx).check("primaryKeys", keys.len() as i64)?;
+        let query = Self::filter_by_primary_keys(query, keys.clone());
+        let query = ctx.modify_query(select, query)?;
+        let query = <_ as SelectDsl<_>>::select(
+            query,
+            (
+                <Self::Table as Table>::PrimaryKey::default(),
+                Self::get_select(select)?,
+            ),
+        );
+        #[cfg(feature = "debug")]
+        {
+            log::debug!("{:?}", diesel::debug_query(&query));
+        }
+        let rows: Vec<(_, _)> = <_ as RunQueryDsl<_>>::load(query, ctx.get_connection())?;
+        let (ids, placeholder): (Vec<_>, Vec<_>) = rows.into_iter().unzip();
+        let values = Self::FieldList::resolve(
+            placeholder,
+            select.arguments(),
+            select,
+            selection,
+            Self::FIELD_NAMES,
+            executor,
+        )?;
+        let entities = ids.into_iter().zip(values).collect::<HashMap<_, _>>();
+        Ok(keys
+            .iter()
+            .map(|key| entities.get(key).cloned().unwrap_or(juniper::Value::Null))
+            .collect())
+    }
+
+    /// Restrict `query` to the entities with one of the given primary keys
+    ///
+    /// The default implementation combines a comparison for each key
+    /// by `OR`. `#[derive(WundergraphEntity)]` generates an implementation
+    /// using a single `IN` expression for entities with a single primary
+    /// key column.
+    ///
+    /// Composite primary keys keep the `OR` chain, as diesel provides no
+    /// row value `IN` expression (`(a, b) IN ((1, 2), ...)`) and SQLite only
+    /// supports those since 3.15. All supported databases answer each
+    /// `a = 1 AND b = 2` term by the primary key index, so the chain only
+    /// grows the query text. Its length is bounded by the maximal limit of
+    /// the entity, as `load_by_primary_keys` rejects requests for more keys
+    fn filter_by_primary_keys<'a>(
+        mut query: BoxedQuery<'a, Self, DB, Ctx>,
+        keys: Vec<<<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed>,
+    ) -> BoxedQuery<'a, Self, DB, Ctx>
+    where
+        Self: 'static,
+        &'static Self: Identifiable,
+        <&'static Self as Identifiable>::Id: UnRef<'static>,
+        <Self::Table as Table>::PrimaryKey:
+            EqAll<<<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed> + Default,
+        <<Self::Table as Table>::PrimaryKey as EqAll<
+            <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+        >>::Output: AppearsOnTable<Self::Table> + NonAggregate + QueryFragment<DB>,
+    {
+        for key in keys {
+            query = <_ as OrFilterDsl<_>>::or_filter(
+                query,
+                <Self::Table as Table>::PrimaryKey::default().eq_all(key),
+            );
+        }
+        query
+    }
+
+    /// Load a single entity by the value of a field listed in `UNIQUE_FIELDS`
+    ///
+    /// The value is read from the argument named like the field. The
+    /// default implementation calls `load` internally
+    fn load_by_unique_field<'a>(
+        field: &'static str,
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        mut query: BoxedQuery<'a, Self, DB, Ctx>,
+    ) -> Result<Option<juniper::Value<WundergraphScalarValue>>>
+    where
+        Self: 'static,
+        Self::Table: 'static,
+        <Self::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<Self::Table>,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        DB: HasSqlType<
+            SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+        >,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    {
+        use juniper::LookAheadMethods;
+        let value = select
+            .argument(field)
+            .ok_or_else(|| WundergraphError::NoUniqueArgumentFound {
+                name: field.to_owned(),
+            })?
+            .value();
+        let condition = LookAheadValue::Object(vec![(
+            field,
+            LookAheadValue::Object(vec![("eq", value.clone())]),
+        )]);
+        let filter = Filter::<Self::Filter, Self::Table>::from_look_ahead_checked(
+            field,
+            &condition,
+            Self::STRICT_FILTER,
+        )?;
+        if let Some(filter) = <_ as BuildFilter<DB>>::into_filter(filter) {
+            query = <_ as FilterDsl<_>>::filter(query, filter);
+        }
+        query = <_ as QueryDsl>::limit(query, 1);
+        let res = Self::load(select, selection, executor, query)?;
+        Ok(res.into_iter().next())
+    }
+
+    /// Count the entities matching the filter of a given graphql request
+    ///
+    /// The default implementation applies `apply_filter` and the
+    /// `QueryModifier` of the context, `limit` and `offset` are ignored
+    fn load_count<'a>(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<i64>
+    where
+        Self::Table: BoxedDsl<
+                'a,
+                DB,
+                Output = BoxedSelectStatement<
+                    'a,
+                    SqlTypeOf<<Self::Table as Table>::AllColumns>,
+                    Self::Table,
+                    DB,
+                >,
+            > + 'static,
+        <Self::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<Self::Table>,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        DB: HasSqlType<BigInt>,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+        i64: FromSql<BigInt, DB>,
+    {
+        use diesel::RunQueryDsl;
+
+        let ctx = executor.context();
+        let query =
+            <_ as SelectDsl<_>>::select(Self::table().into_boxed(), Self::get_select(select)?);
+        let query = Self::apply_filter(query, select)?;
+        let query = ctx.modify_query(select, query)?;
+        let query = <_ as SelectDsl<_>>::select(query, diesel::dsl::count_star());
+        Ok(<_ as RunQueryDsl<_>>::get_result(
+            query,
+            ctx.get_connection(),
+        )?)
+    }
+
+    /// Load the aggregates of all entities matching the filter argument
+    ///
+    /// `select` and `selection` refer to the `{name}Aggregate` field. The
+    /// count and all requested aggregates are loaded by a single query via
+    /// `load_aggregate_values`, groups are loaded by `load_aggregate_groups`
+    fn load_aggregates<'a>(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<juniper::Value<WundergraphScalarValue>>
+    where
+        Self::Table: BoxedDsl<
+                'a,
+                DB,
+                Output = BoxedSelectStatement<
+                    'a,
+                    SqlTypeOf<<Self::Table as Table>::AllColumns>,
+                    Self::Table,
+                    DB,
+                >,
+            > + 'static,
+        <Self::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<Self::Table>,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        DB: HasSqlType<(GroupKeySqlType<Self, DB, Ctx>, BigInt)>,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+        (GroupKey<Self, DB, Ctx>, i64): Queryable<(GroupKeySqlType<Self, DB, Ctx>, BigInt), DB>,
+    {
+        use juniper::LookAheadMethods;
+
+        let ctx = executor.context();
+        let requested = aggregate::requested_aggregates(selection);
+        // Without grouping columns the query returns exactly one row
+        let row = if select.has_child("count") || !requested.is_empty() {
+            let query = AggregateQuery::new(select, &[], ctx)?;
+            Self::load_aggregate_values(&requested, query, executor)?
+                .into_iter()
+                .next()
+        } else {
+            None
+        };
+        aggregate::try_build_object(selection, |name, fields| match name {
+            "__typename" => Ok(Some(juniper::Value::scalar(format!(
+                "{}Aggregate",
+                Self::TYPE_NAME
+            )))),
+            "count" => Ok(row.as_ref().map(|row| juniper::Value::scalar(row.count))),
+            "groups" => {
+                let groups = select.select_child("groups").ok_or_else(|| {
+                    WundergraphError::InvalidGroupByArgument {
+                        path: String::from("groupBy"),
+                    }
+                })?;
+                let (name, alias, pos, _) = get_sub_field("groups", selection);
+                let executor = executor.field_sub_executor(alias, name, pos, fields);
+                Self::load_aggregate_groups(select, groups, fields, &executor).map(Some)
+            }
+            name => match AggregateFunction::from_field_name(name) {
+                Some(function) => aggregate::try_build_object(fields, |field, _| {
+                    if field == "__typename" {
+                        return Ok(Some(juniper::Value::scalar(format!(
+                            "{}{}",
+                            Self::TYPE_NAME,
+                            function.type_name_suffix()
+                        ))));
+                    }
+                    Ok(Some(
+                        requested
+                            .iter()
+                            .position(|r| *r == (function, field))
+                            .and_then(|position| row.as_ref()?.values.get(position).cloned())
+                            .unwrap_or_else(juniper::Value::null),
+                    ))
+                })
+                .map(Some),
+                None => Ok(None),
+            },
+        })
+    }
+
+    /// Load the aggregates of each group of entities matching the filter argument
+    ///
+    /// `select` refers to the `{name}Aggregate` field, `groups` and `selection`
+    /// to its `groups` field. The groups given by the `groupBy` argument are
+    /// loaded together with their count and all requested aggregates by a
+    /// single query via `load_aggregate_values`
+    fn load_aggregate_groups<'a>(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        groups: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<juniper::Value<WundergraphScalarValue>>
+    where
+        Self::Table: BoxedDsl<
+                'a,
+                DB,
+                Output = BoxedSelectStatement<
+                    'a,
+                    SqlTypeOf<<Self::Table as Table>::AllColumns>,
+                    Self::Table,
+                    DB,
+                >,
+            > + 'static,
+        <Self::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<Self::Table>,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        DB: HasSqlType<(GroupKeySqlType<Self, DB, Ctx>, BigInt)>,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+        (GroupKey<Self, DB, Ctx>, i64): Queryable<(GroupKeySqlType<Self, DB, Ctx>, BigInt), DB>,
+    {
+        use juniper::LookAheadMethods;
+
+        let ctx = executor.context();
+        let group_by = aggregate::group_by_columns::<Self, DB, Ctx>(groups)?;
+        let grouped = group_by
+            .iter()
+            .map(|local_index| {
+                Self::FieldList::map_table_field(*local_index, |global| Self::FIELD_NAMES[global])
+                    .expect("Field is there")
+            })
+            .collect::<Vec<_>>();
+        let key = groups.select_child("key");
+        // Fields not part of the group are not loaded
+        if let Some(key) = key {
+            if let Some(name) = Self::FIELD_NAMES
+                .iter()
+                .find(|name| key.has_child(name) && !grouped.contains(*name))
+            {
+                return Err(WundergraphError::UngroupedField {
+                    name: (*name).to_owned(),
+                });
+            }
+        }
+
+        let requested = aggregate::requested_aggregates(selection);
+        let query = AggregateQuery::new(select, &group_by, ctx)?;
+        let rows = Self::load_aggregate_values(&requested, query, executor)?;
+        let (placeholder, rows): (Vec<_>, Vec<_>) = rows
+            .into_iter()
+            .map(|row| (row.key, (row.count, row.values)))
+            .unzip();
+        let keys = if let Some(key) = key {
+            let (name, alias, pos, key_selection) = get_sub_field("key", selection);
+            let executor = executor.field_sub_executor(alias, name, pos, key_selection);
+            Self::FieldList::resolve(
+                placeholder,
+                key.arguments(),
+                key,
+                key_selection,
+                Self::FIELD_NAMES,
+                &executor,
+            )?
+        } else {
+            Vec::new()
+        };
+
+        let groups = rows
+            .iter()
+            .enumerate()
+            .map(|(idx, (count, values))| {
+                aggregate::try_build_object(selection, |name, fields| match name {
+                    "__typename" => Ok(Some(juniper::Value::scalar(format!(
+                        "{}AggregateGroup",
+                        Self::TYPE_NAME
+                    )))),
+                    "key" => Ok(keys.get(idx).cloned()),
+                    "count" => Ok(Some(juniper::Value::scalar(*count))),
+                    name => match AggregateFunction::from_field_name(name) {
+                        Some(function) => aggregate::try_build_object(fields, |field, _| {
+                            if field == "__typename" {
+                                return Ok(Some(juniper::Value::scalar(format!(
+                                    "{}{}",
+                                    Self::TYPE_NAME,
+                                    function.type_name_suffix()
+                                ))));
+                            }
+                            Ok(Some(
+                                requested
+                                    .iter()
+                                    .position(|r| *r == (function, field))
+                                    .and_then(|position| values.get(position).cloned())
+                                    .unwrap_or_else(juniper::Value::null),
+                            ))
+                        })
+                        .map(Some),
+                        None => Ok(None),
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(juniper::Value::list(groups))
+    }
+
+    /// Load a page of entities as relay connection
+    ///
+    /// `select` and `selection` refer to the connection field. The default
+    /// implementation builds the query with `build_query`, reads the
+    /// selected fields from `edges { node }` and applies the window given by
+    /// the `first`, `after`, `last` and `before` arguments
+    fn load_connection<'a>(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<juniper::Value<WundergraphScalarValue>>
+    where
+        Self::Table: BoxedDsl<
+                'a,
+                DB,
+                Output = BoxedSelectStatement<
+                    'a,
+                    SqlTypeOf<<Self::Table as Table>::AllColumns>,
+                    Self::Table,
+                    DB,
+                >,
+            > + 'static,
+        <Self::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<Self::Table>,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        DB: HasSqlType<BigInt>
+            + HasSqlType<
+                SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+            >,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+        i64: FromSql<BigInt, DB>,
+    {
+        use juniper::LookAheadMethods;
+
+        let policy = LimitPolicy::for_entity::<Self, DB, Ctx>(executor.context());
+        let arguments = connection::ConnectionArguments::from_select(select)?.limited(&policy)?;
+        let window = arguments.window(|| Self::load_count(select, executor))?;
+        let node = connection::node_look_ahead(select).unwrap_or(select);
+        let (name, alias, pos, edges) = get_sub_field("edges", selection);
+        let executor = executor.field_sub_executor(alias, name, pos, edges);
+        let (name, alias, pos, node_selection) = get_sub_field("node", edges);
+        let executor = executor.field_sub_executor(alias, name, pos, node_selection);
+
+        // The connection field carries the filter and order arguments,
+        // while the selected fields are read from `edges { node }`
+        let query = Self::build_query(&[], select)?;
+        let query = <_ as SelectDsl<_>>::select(query, Self::get_select(node)?);
+        let query = window.apply::<Self, DB, Ctx>(query);
+
+        let nodes = Self::load(node, node_selection, &executor, query)?;
+        let total_count = if select.has_child("totalCount") {
+            Some(Self::load_count(select, &executor)?)
+        } else {
+            None
+        };
+        Ok(window.build_response(Self::TYPE_NAME, nodes, total_count, selection))
+    }
+
+    /// Load a page of entities as relay connection using keyset pagination
+    ///
+    /// `select` and `selection` refer to the connection field. Cursors
+    /// encode the values of the order columns and the primary key of an
+    /// entity, `after` and `before` are compiled to a where clause comparing
+    /// those columns, so loading a page does not require to skip all
+    /// preceding entities
+    fn load_keyset_connection<'a>(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<juniper::Value<WundergraphScalarValue>>
+    where
+        Self::Table: BoxedDsl<
+                'a,
+                DB,
+                Output = BoxedSelectStatement<
+                    'a,
+                    SqlTypeOf<<Self::Table as Table>::AllColumns>,
+                    Self::Table,
+                    DB,
+                >,
+            > + 'static,
+        <Self::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<Self::Table>,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        DB: HasSqlType<BigInt>
+            + HasSqlType<
+                SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+            >,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+        i64: FromSql<BigInt, DB>,
+    {
+        use diesel::RunQueryDsl;
+        use juniper::LookAheadMethods;
+
+        let columns = connection::keyset_columns::<Self, DB, Ctx>(select, executor)?;
+        let policy = LimitPolicy::for_entity::<Self, DB, Ctx>(executor.context());
+        let arguments =
+            connection::KeysetArguments::from_select(select, &columns)?.limited(&policy)?;
+        let node = connection::node_look_ahead(select).unwrap_or(select);
+        let (name, alias, pos, edges) = get_sub_field("edges", selection);
+        let executor = executor.field_sub_executor(alias, name, pos, edges);
+        let (name, alias, pos, node_selection) = get_sub_field("node", edges);
+        let executor = executor.field_sub_executor(alias, name, pos, node_selection);
+
+        let field_name = |local_index| {
+            Self::FieldList::map_table_field(local_index, |global| Self::FIELD_NAMES[global])
+                .expect("Field is there")
+        };
+        let indices = columns.iter().map(|c| c.index).collect::<Vec<_>>();
+        // The key columns are always selected, as they are required
+        // to build the cursors
+        let select_clause = <Self::Columns as BuildSelect<Self::Table, DB, _>>::build_select(
+            node,
+            field_name,
+            |index| indices.contains(&index),
+            true,
+        )?;
+        let mut query = <_ as SelectDsl<_>>::select(Self::table().into_boxed(), select_clause);
+        query = Self::apply_filter(query, select)?;
+        for (name, condition) in arguments.conditions(&columns) {
+            let filter = Filter::<Self::Filter, Self::Table>::from_look_ahead_checked(
+                name,
+                &condition,
+                Self::STRICT_FILTER,
+            )?;
+            if let Some(filter) = <_ as BuildFilter<DB>>::into_filter(filter) {
+                query = <_ as FilterDsl<_>>::filter(query, filter);
+            }
+        }
+        let order = <Self::Columns as BuildOrder<Self::Table, DB>>::build_order(
+            &arguments.order(&columns),
+            field_name,
+            |_, _, _| None,
+        )?;
+        for s in order {
+            query = query.then_order_by(s);
+        }
+        if let Some(limit) = arguments.limit() {
+            query = <_ as LimitDsl>::limit(query, limit);
+        }
+
+        let ctx = executor.context();
+        let query = ctx.modify_query(node, query)?;
+        #[cfg(feature = "debug")]
+        {
+            log::debug!("{:?}", diesel::debug_query(&query));
+        }
+        let mut placeholder = <_ as RunQueryDsl<_>>::load(query, ctx.get_connection())?;
+        let (has_previous_page, has_next_page) = arguments.page(&mut placeholder);
+        let total_count = if select.has_child("totalCount") {
+            Some(Self::load_count(select, &executor)?)
+        } else {
+            None
+        };
+        let (nodes, values) = Self::FieldList::resolve_with_columns(
+            placeholder,
+            &indices,
+            node.arguments(),
+            node,
+            node_selection,
+            Self::FIELD_NAMES,
+            &executor,
+        )?;
+        let cursors = values
+            .iter()
+            .map(|values| connection::encode_keyset_cursor(&columns, values))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(connection::build_connection(
+            Self::TYPE_NAME,
+            &nodes,
+            &cursors,
+            has_previous_page,
+            has_next_page,
+            total_count,
+            selection,
+        ))
+    }
+
     /// Build a sql query to load this entity from a given graphql request
     ///
     /// The default implementation calls `get_select`, `apply_filter`,
@@ -333,7 +1101,8 @@ where
             Self::PrimaryKeyIndex::is_index,
             (0..Self::FieldList::NON_TABLE_FIELD_COUNT).any(|i| {
                 Self::FieldList::map_non_table_field(i, |global| {
-                    select.has_child(Self::FIELD_NAMES[global])
+                    let name = Self::FIELD_NAMES[global];
+                    select.has_child(name) || select.has_child(&format!("{}Count", name))
                 })
                 .unwrap_or(false)
             }),
@@ -341,8 +1110,12 @@ where
     }
 
     /// Construct a where clause from a given graphql request
+    ///
+    /// Applies the `filter` argument and, if `FULL_TEXT_SEARCH` is set,
+    /// the `search` argument. Returns an error if the filter argument
+    /// contains any value that could not be interpreted
     fn apply_filter<'a>(
-        query: BoxedQuery<'a, Self, DB, Ctx>,
+        mut query: BoxedQuery<'a, Self, DB, Ctx>,
         select: &LookAheadSelection<'_, WundergraphScalarValue>,
     ) -> Result<BoxedQuery<'a, Self, DB, Ctx>>
     where
@@ -351,49 +1124,124 @@ where
     {
         use juniper::LookAheadMethods;
         if let Some(filter) = select.argument("filter") {
-            if let Some(filter) =
-                <Filter<Self::Filter, Self::Table> as FromLookAheadValue>::from_look_ahead(
-                    filter.value(),
-                )
-                .and_then(<_ as BuildFilter<DB>>::into_filter)
-            {
-                Ok(<_ as FilterDsl<_>>::filter(query, filter))
-            } else {
-                Ok(query)
+            let filter = Filter::<Self::Filter, Self::Table>::from_look_ahead_checked(
+                "filter",
+                filter.value(),
+                Self::STRICT_FILTER,
+            )?;
+            if let Some(filter) = <_ as BuildFilter<DB>>::into_filter(filter) {
+                query = <_ as FilterDsl<_>>::filter(query, filter);
             }
-        } else {
-            Ok(query)
+        }
+        if let Some(search) = Self::full_text_search_query(select)? {
+            if let Some(filter) = Self::build_full_text_search_filter(&search) {
+                query = <_ as FilterDsl<_>>::filter(query, filter);
+            }
+        }
+        Ok(query)
+    }
+
+    /// Read the `search` argument from a given graphql request
+    ///
+    /// Returns `None` if `FULL_TEXT_SEARCH` is not set or if
+    /// no `search` argument is given
+    fn full_text_search_query(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    ) -> Result<Option<String>> {
+        use juniper::LookAheadMethods;
+        if !Self::FULL_TEXT_SEARCH {
+            return Ok(None);
+        }
+        match select.argument("search").map(LookAheadArgument::value) {
+            None | Some(LookAheadValue::Null) => Ok(None),
+            Some(v) => String::from_look_ahead(v).map(Some).ok_or_else(|| {
+                WundergraphError::InvalidFilterArgument {
+                    path: String::from("search"),
+                }
+            }),
         }
     }
 
     /// Construct a order clause from a given graphql request
+    ///
+    /// Falls back to `DEFAULT_ORDER` if no `order` argument is given
     fn apply_order<'a>(
         mut query: BoxedQuery<'a, Self, DB, Ctx>,
         select: &LookAheadSelection<'_, WundergraphScalarValue>,
     ) -> Result<BoxedQuery<'a, Self, DB, Ctx>>
+    where
+        Self::Table: 'static,
+    {
+        for s in Self::build_order_clauses(select)? {
+            query = query.then_order_by(s);
+        }
+        Ok(query)
+    }
+
+    /// Build the order clauses requested by a given graphql request
+    ///
+    /// Used by `apply_order`, falls back to `DEFAULT_ORDER` if no `order`
+    /// argument is given
+    fn build_order_clauses(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    ) -> Result<OrderClauses<Self::Table, DB>>
     where
         Self::Table: 'static,
     {
         use juniper::LookAheadMethods;
-        match select.argument("order").map(LookAheadArgument::value) {
-            Some(LookAheadValue::List(order)) => {
-                let order_stmts = <Self::Columns as BuildOrder<Self::Table, DB>>::build_order(
-                    order,
-                    |local_index| {
-                        Self::FieldList::map_table_field(local_index, |global| {
-                            Self::FIELD_NAMES[global]
-                        })
-                        .expect("Field is there")
-                    },
-                )?;
-                for s in order_stmts {
-                    query = query.then_order_by(s);
-                }
-                Ok(query)
+        let default_order;
+        let order = match select.argument("order").map(LookAheadArgument::value) {
+            Some(LookAheadValue::List(order)) => &order[..],
+            Some(_) => {
+                return Err(WundergraphError::InvalidOrderArgument {
+                    path: String::from("order"),
+                })
+            }
+            None => {
+                default_order = Self::DEFAULT_ORDER
+                    .iter()
+                    .map(|(column, order)| {
+                        let direction = match order {
+                            Order::Asc => "ASC",
+                            Order::Desc => "DESC",
+                        };
+                        LookAheadValue::Object(vec![
+                            ("column", LookAheadValue::Enum(column)),
+                            ("direction", LookAheadValue::Enum(direction)),
+                        ])
+                    })
+                    .collect::<Vec<_>>();
+                &default_order[..]
+            }
+        };
+        let search = Self::full_text_search_query(select)?;
+        if search.is_none() {
+            // Ordering by relevance requires a search query
+            let relevance = order.iter().position(|o| match o {
+                LookAheadValue::Object(o) => o
+                    .iter()
+                    .any(|(k, v)| *k == "column" && *v == LookAheadValue::Enum(RELEVANCE)),
+                _ => false,
+            });
+            if let Some(idx) = relevance {
+                return Err(WundergraphError::InvalidOrderArgument {
+                    path: format!("order[{}].column", idx),
+                });
             }
-            Some(_) => Err(WundergraphError::CouldNotBuildFilterArgument),
-            None => Ok(query),
         }
+        <Self::Columns as BuildOrder<Self::Table, DB>>::build_order(
+            order,
+            |local_index| {
+                Self::FieldList::map_table_field(local_index, |global| Self::FIELD_NAMES[global])
+                    .expect("Field is there")
+            },
+            |column, order, nulls| match search {
+                Some(ref search) if column == RELEVANCE => {
+                    Self::build_relevance_order(search, order, nulls)
+                }
+                _ => Self::build_reference_order(column, order, nulls),
+            },
+        )
     }
 
     /// Construct a limit clause from a given graphql request
@@ -406,13 +1254,43 @@ where
             Ok(<_ as LimitDsl>::limit(
                 query,
                 i64::from_look_ahead(limit.value())
-                    .ok_or(WundergraphError::CouldNotBuildFilterArgument)?,
+                    .filter(|l| *l >= 0)
+                    .ok_or(WundergraphError::InvalidLimitArgument)?,
             ))
         } else {
             Ok(query)
         }
     }
 
+    /// Enforce the default and maximal limit of a top level query
+    ///
+    /// Rejects a `limit` argument greater than the maximal limit and
+    /// adds the default limit if no `limit` argument is given, see
+    /// [`LimitPolicy`](limit/struct.LimitPolicy.html)
+    fn apply_limit_policy<'a>(
+        query: BoxedQuery<'a, Self, DB, Ctx>,
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        ctx: &Ctx,
+    ) -> Result<BoxedQuery<'a, Self, DB, Ctx>>
+    where
+        Ctx: WundergraphContext,
+    {
+        use juniper::LookAheadMethods;
+        let requested = select
+            .argument("limit")
+            .map(|l| {
+                i64::from_look_ahead(l.value())
+                    .filter(|l| *l >= 0)
+                    .ok_or(WundergraphError::InvalidLimitArgument)
+            })
+            .transpose()?;
+        match LimitPolicy::for_entity::<Self, DB, Ctx>(ctx).limit("limit", requested)? {
+            // Replaces a limit added by `apply_limit` or `apply_offset`
+            Some(limit) if requested.is_none() => Ok(<_ as LimitDsl>::limit(query, limit)),
+            _ => Ok(query),
+        }
+    }
+
     /// Construct a offset clause from a given grahpql request
     fn apply_offset<'a>(
         query: BoxedQuery<'a, Self, DB, Ctx>,
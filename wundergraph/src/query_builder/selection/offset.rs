@@ -1,14 +1,10 @@
-use crate::error::Result;
-#[cfg(any(feature = "postgres", feature = "sqlite"))]
-use crate::error::WundergraphError;
-#[cfg(any(feature = "postgres", feature = "sqlite"))]
+use crate::error::{Result, WundergraphError};
 use crate::juniper_ext::FromLookAheadValue;
 use crate::query_builder::selection::{BoxedQuery, LoadingHandler};
 use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
-#[cfg(feature = "sqlite")]
+#[cfg(any(feature = "sqlite", feature = "mysql"))]
 use diesel::query_dsl::methods::LimitDsl;
-#[cfg(any(feature = "postgres", feature = "sqlite"))]
 use diesel::query_dsl::methods::OffsetDsl;
 use juniper::LookAheadSelection;
 
@@ -16,16 +12,9 @@ use juniper::LookAheadSelection;
 /// clauses in different database systems
 pub trait ApplyOffset: Backend {
     /// Add a offset clause to the given query if requested
-    fn apply_offset<'a, L, Ctx>(
-        query: BoxedQuery<'a, L, Self, Ctx>,
-        select: &LookAheadSelection<'_, WundergraphScalarValue>,
-    ) -> Result<BoxedQuery<'a, L, Self, Ctx>>
-    where
-        L: LoadingHandler<Self, Ctx>;
-}
-
-#[cfg(feature = "postgres")]
-impl ApplyOffset for diesel::pg::Pg {
+    ///
+    /// The default implementation passes the `offset` argument to
+    /// `apply_offset_value`
     fn apply_offset<'a, L, Ctx>(
         query: BoxedQuery<'a, L, Self, Ctx>,
         select: &LookAheadSelection<'_, WundergraphScalarValue>,
@@ -35,40 +24,74 @@ impl ApplyOffset for diesel::pg::Pg {
     {
         use juniper::LookAheadMethods;
         if let Some(offset) = select.argument("offset") {
-            Ok(<_ as OffsetDsl>::offset(
+            let offset = i64::from_look_ahead(offset.value())
+                .filter(|o| *o >= 0)
+                .ok_or(WundergraphError::InvalidOffsetArgument)?;
+            Ok(Self::apply_offset_value::<L, Ctx>(
                 query,
-                i64::from_look_ahead(offset.value())
-                    .ok_or(WundergraphError::CouldNotBuildFilterArgument)?,
+                offset,
+                select.argument("limit").is_some(),
             ))
         } else {
             Ok(query)
         }
     }
+
+    /// Add a offset clause skipping `offset` rows to the given query
+    ///
+    /// `has_limit` indicates if the query already contains a limit clause
+    fn apply_offset_value<'a, L, Ctx>(
+        query: BoxedQuery<'a, L, Self, Ctx>,
+        offset: i64,
+        _has_limit: bool,
+    ) -> BoxedQuery<'a, L, Self, Ctx>
+    where
+        L: LoadingHandler<Self, Ctx>,
+    {
+        <_ as OffsetDsl>::offset(query, offset)
+    }
 }
 
+#[cfg(feature = "postgres")]
+impl ApplyOffset for diesel::pg::Pg {}
+
 #[cfg(feature = "sqlite")]
 impl ApplyOffset for diesel::sqlite::Sqlite {
-    fn apply_offset<'a, L, Ctx>(
+    fn apply_offset_value<'a, L, Ctx>(
         query: BoxedQuery<'a, L, Self, Ctx>,
-        select: &LookAheadSelection<'_, WundergraphScalarValue>,
-    ) -> Result<BoxedQuery<'a, L, Self, Ctx>>
+        offset: i64,
+        has_limit: bool,
+    ) -> BoxedQuery<'a, L, Self, Ctx>
     where
         L: LoadingHandler<Self, Ctx>,
     {
-        use juniper::LookAheadMethods;
-        if let Some(offset) = select.argument("offset") {
-            let q = <_ as OffsetDsl>::offset(
-                query,
-                i64::from_look_ahead(offset.value())
-                    .ok_or(WundergraphError::CouldNotBuildFilterArgument)?,
-            );
-            if select.argument("limit").is_some() {
-                Ok(q)
-            } else {
-                Ok(<_ as LimitDsl>::limit(q, -1))
-            }
+        let q = <_ as OffsetDsl>::offset(query, offset);
+        // sqlite does not support a offset clause without limit clause
+        if has_limit {
+            q
         } else {
-            Ok(query)
+            <_ as LimitDsl>::limit(q, -1)
+        }
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl ApplyOffset for diesel::mysql::Mysql {
+    fn apply_offset_value<'a, L, Ctx>(
+        query: BoxedQuery<'a, L, Self, Ctx>,
+        offset: i64,
+        has_limit: bool,
+    ) -> BoxedQuery<'a, L, Self, Ctx>
+    where
+        L: LoadingHandler<Self, Ctx>,
+    {
+        let q = <_ as OffsetDsl>::offset(query, offset);
+        // mysql does not support a offset clause without limit clause
+        // and has no way to express "no limit" other than a huge number
+        if has_limit {
+            q
+        } else {
+            <_ as LimitDsl>::limit(q, i64::max_value())
         }
     }
 }
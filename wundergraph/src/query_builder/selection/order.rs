@@ -8,20 +8,61 @@ use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
 use diesel::expression::NonAggregate;
 use diesel::query_builder::QueryFragment;
-use diesel::{BoxableExpression, Column, ExpressionMethods, QuerySource, SelectableExpression};
+use diesel::sql_types::SingleValue;
+use diesel::{
+    BoxableExpression, Column, Expression, ExpressionMethods, QuerySource, SelectableExpression,
+};
 use juniper::{
     meta, FromInputValue, GraphQLEnum, GraphQLType, LookAheadValue, Registry, ToInputValue,
 };
 use std::marker::PhantomData;
 
+/// Name of the order column that orders by full text search relevance
+pub const RELEVANCE: &str = "RELEVANCE";
+
 /// Build a order clause out of a given GraphQL request
 pub trait BuildOrder<T, DB> {
     /// Uses the given order argument to build a valid order
     /// clause for the wundergraph entity `T`
+    ///
+    /// `reference` builds order clauses for columns not part of `T`,
+    /// including `RELEVANCE`
     fn build_order(
         order: &[LookAheadValue<'_, WundergraphScalarValue>],
         field_name: impl Fn(usize) -> &'static str,
-    ) -> Result<Vec<Box<dyn BoxableExpression<T, DB, SqlType = ()>>>>;
+        reference: impl Fn(&str, Order, Option<Nulls>) -> Option<OrderClauses<T, DB>>,
+    ) -> Result<OrderClauses<T, DB>>;
+}
+
+/// A list of order clauses for the table `T`
+pub type OrderClauses<T, DB> = Vec<Box<dyn BoxableExpression<T, DB, SqlType = ()>>>;
+
+/// Build the order clauses for ordering by `expr`
+///
+/// The placement of `NULL` values is emulated by ordering by `expr IS NULL`
+/// first, so that all backends return the same order.
+pub fn order_by_expression<T, DB, E>(
+    expr: E,
+    order: Order,
+    nulls: Option<Nulls>,
+) -> OrderClauses<T, DB>
+where
+    DB: Backend,
+    E: Expression + SelectableExpression<T> + NonAggregate + QueryFragment<DB> + Clone + 'static,
+    E::SqlType: SingleValue,
+{
+    let mut ret = Vec::with_capacity(2);
+    match nulls {
+        Some(Nulls::First) => ret.push(Box::new(expr.clone().is_null().desc()) as Box<_>),
+        Some(Nulls::Last) => ret.push(Box::new(expr.clone().is_null().asc()) as Box<_>),
+        None => {}
+    }
+    if order == Order::Desc {
+        ret.push(Box::new(expr.desc()) as Box<_>);
+    } else {
+        ret.push(Box::new(expr.asc()) as Box<_>);
+    }
+    ret
 }
 
 /// Defines how to order the result of an query
@@ -33,6 +74,15 @@ pub enum Order {
     Desc,
 }
 
+/// Defines where `NULL` values are placed in an ordered result
+#[derive(Debug, GraphQLEnum, Copy, Clone, PartialEq)]
+pub enum Nulls {
+    /// Place `NULL` values before all other values
+    First,
+    /// Place `NULL` values after all other values
+    Last,
+}
+
 #[derive(Debug)]
 pub struct OrderBy<L, DB, Ctx>(PhantomData<(L, DB, Ctx)>);
 
@@ -48,6 +98,20 @@ pub struct GraphqlOrderWrapper<T, DB, Ctx>(PhantomData<(T, DB, Ctx)>);
 #[derive(Debug)]
 pub struct OrderTypeInfo<L, DB, Ctx>(String, PhantomData<(L, DB, Ctx)>);
 
+impl FromLookAheadValue for Nulls {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Enum(e) = *v {
+            match e {
+                "FIRST" => Some(Nulls::First),
+                "LAST" => Some(Nulls::Last),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+}
+
 impl FromLookAheadValue for Order {
     fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
         if let LookAheadValue::Enum(e) = *v {
@@ -114,6 +178,7 @@ where
         let args = &[
             registry.arg::<GraphqlOrderWrapper<T, DB, Ctx>>("column", &Default::default()),
             registry.arg_with_default("direction", &Order::Asc, &()),
+            registry.arg::<Option<Nulls>>("nulls", &()),
         ];
 
         let obj = registry.build_input_object_type::<Self>(info, args);
@@ -204,15 +269,17 @@ macro_rules! impl_order_traits {
             where Table: ::diesel::Table,
                   DB: Backend,
             $($T: Column<Table = Table> + ExpressionMethods + Copy + Default +
-              SelectableExpression<Table> + NonAggregate + QueryFragment<DB> + 'static,)+
+              SelectableExpression<Table> + NonAggregate + QueryFragment<DB> + 'static,
+              <$T as Expression>::SqlType: SingleValue,)+
             {
                 fn build_order(
                     fields: &[LookAheadValue<'_, WundergraphScalarValue>],
                     field_name: impl Fn(usize) -> &'static str,
-                ) -> Result<Vec<Box<dyn BoxableExpression<Table, DB, SqlType = ()>>>>
+                    reference: impl Fn(&str, Order, Option<Nulls>) -> Option<OrderClauses<Table, DB>>,
+                ) -> Result<OrderClauses<Table, DB>>
                 {
                     let mut ret = Vec::with_capacity(fields.len());
-                    for f in fields {
+                    for (idx, f) in fields.iter().enumerate() {
                         if let LookAheadValue::Object(o) = f {
                             let column = o.iter().find(|(k, _)| *k == "column")
                                 .and_then(|(_, v)| if let LookAheadValue::Enum(c) = v {
@@ -220,29 +287,43 @@ macro_rules! impl_order_traits {
                                 } else {
                                     None
                                 })
-                                .ok_or(WundergraphError::CouldNotBuildFilterArgument)?;
-                            let order = o.iter().find(|(k, _)| *k == "direction")
-                                .and_then(|(_, v)| Order::from_look_ahead(v))
-                                .unwrap_or(Order::Asc);
+                                .ok_or_else(|| WundergraphError::InvalidOrderArgument {
+                                    path: format!("order[{}].column", idx),
+                                })?;
+                            let order = match o.iter().find(|(k, _)| *k == "direction") {
+                                None | Some((_, LookAheadValue::Null)) => Order::Asc,
+                                Some((_, v)) => Order::from_look_ahead(v).ok_or_else(|| {
+                                    WundergraphError::InvalidOrderArgument {
+                                        path: format!("order[{}].direction", idx),
+                                    }
+                                })?,
+                            };
+                            let nulls = match o.iter().find(|(k, _)| *k == "nulls") {
+                                None | Some((_, LookAheadValue::Null)) => None,
+                                Some((_, v)) => Some(Nulls::from_look_ahead(v).ok_or_else(|| {
+                                    WundergraphError::InvalidOrderArgument {
+                                        path: format!("order[{}].nulls", idx),
+                                    }
+                                })?),
+                            };
                             match *column {
                             $(
-                                x if x == field_name($idx) => if order == Order::Desc {
-                                    ret.push(Box::new($T::default().desc())
-                                             as Box<dyn BoxableExpression<Table, DB, SqlType = ()>>)
-                                } else {
-                                    ret.push(Box::new($T::default().asc()) as Box<_>)
+                                x if x == field_name($idx) => {
+                                    ret.extend(order_by_expression($T::default(), order, nulls));
                                 }
                             )+
-                                x => {
+                                x => if let Some(o) = reference(x, order, nulls) {
+                                    ret.extend(o)
+                                } else {
                                     return Err(WundergraphError::UnknownDatabaseField {
                                             name: x.to_owned()
                                         });
                                 }
                             }
                         } else {
-                            return Err(
-                                WundergraphError::CouldNotBuildFilterArgument
-                            );
+                            return Err(WundergraphError::InvalidOrderArgument {
+                                path: format!("order[{}]", idx),
+                            });
                         }
                     }
                     Ok(ret)
@@ -267,11 +348,22 @@ macro_rules! impl_order_traits {
                 Fun: Fn(usize) -> &'static str,
                 {
                     use juniper::meta::EnumValue;
-                    let values = [
+                    let mut values = vec![
                         $(
                             EnumValue::new(names($idx)),
                         )*
                     ];
+                    if Loading::FULL_TEXT_SEARCH {
+                        values.push(
+                            EnumValue::new(RELEVANCE)
+                                .description("Order by the relevance for the full text search given by the `search` argument"),
+                        );
+                    }
+                    values.extend(
+                        Loading::reference_order_columns()
+                            .iter()
+                            .map(|name| EnumValue::new(name))
+                    );
                     let e = registry.build_enum_type::<Type>(
                         info,
                         &values,
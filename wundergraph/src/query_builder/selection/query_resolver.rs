@@ -32,6 +32,26 @@ pub trait WundergraphResolvePlaceHolderList<R, DB: Backend, Ctx> {
         selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
         executor: &Executor<Ctx, WundergraphScalarValue>,
     ) -> Result<Vec<juniper::Object<WundergraphScalarValue>>>;
+
+    /// Resolve the field list like `resolve`, additionally returning the
+    /// values of the fields at the given indices for each loaded entity,
+    /// independently of the fields requested by `look_ahead`
+    ///
+    /// Fields that could not be resolved to a single value, like `HasOne`
+    /// relations, are returned as `None`
+    #[allow(clippy::type_complexity)]
+    fn resolve_with_columns(
+        self,
+        columns: &[usize],
+        get_name: impl Fn(usize) -> &'static str,
+        global_args: &[juniper::LookAheadArgument<WundergraphScalarValue>],
+        look_ahead: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<Ctx, WundergraphScalarValue>,
+    ) -> Result<(
+        Vec<juniper::Object<WundergraphScalarValue>>,
+        Vec<Vec<Option<juniper::Value<WundergraphScalarValue>>>>,
+    )>;
 }
 
 macro_rules! wundergraph_add_one_to_index {
@@ -65,24 +85,52 @@ macro_rules! wundergraph_value_impl {
                     selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
                     executor: &Executor<Ctx, WundergraphScalarValue>,
                 ) -> Result<Vec<juniper::Object<WundergraphScalarValue>>>
+                {
+                    <Self as WundergraphResolvePlaceHolderList<($($ST,)*), Back, Ctx>>::resolve_with_columns(
+                        self, &[], get_name, global_args, look_ahead, selection, executor,
+                    ).map(|(objs, _)| objs)
+                }
+
+                fn resolve_with_columns(
+                    self,
+                    columns: &[usize],
+                    get_name: impl Fn(usize) -> &'static str,
+                    global_args: &[juniper::LookAheadArgument<WundergraphScalarValue>],
+                    look_ahead: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
+                    selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+                    executor: &Executor<Ctx, WundergraphScalarValue>,
+                ) -> Result<(
+                    Vec<juniper::Object<WundergraphScalarValue>>,
+                    Vec<Vec<Option<juniper::Value<WundergraphScalarValue>>>>,
+                )>
                 {
                     let mut resolver = (
                         $(<$ST as ResolveWundergraphFieldValue<Back, Ctx>>::Resolver::new(self.len()),)*
                     );
                     let mut objs: Vec<juniper::Object<WundergraphScalarValue>>
                         = vec![juniper::Object::with_capacity(wundergraph_add_one_to_index!($($idx)*)-1); self.len()];
+                    let mut column_values = vec![vec![None; columns.len()]; self.len()];
 
-                    self.into_iter().zip(objs.iter_mut()).map(|(placeholder, obj)|{
+                    self.into_iter().zip(objs.iter_mut()).zip(column_values.iter_mut()).map(|((placeholder, obj), values)|{
                         $(
-                            if let Some(look_ahead) = look_ahead.select_child(get_name($idx)) {
+                            let child = look_ahead.select_child(get_name($idx));
+                            // Columns are resolved even if they are not
+                            // selected, as each value is only resolved once
+                            if child.is_some() || columns.contains(&$idx) {
                                 let (name, alias, pos, selection) = get_sub_field(get_name($idx), selection);
                                 let executor = executor.field_sub_executor(alias, name, pos, selection);
-                                if let Some(value) = resolver.$idx.resolve_value(
+                                let value = resolver.$idx.resolve_value(
                                     placeholder.$idx,
-                                    look_ahead,
+                                    child.unwrap_or(look_ahead),
                                     selection,
                                     &executor
-                                )? {
+                                )?;
+                                for (column, v) in columns.iter().zip(values.iter_mut()) {
+                                    if *column == $idx {
+                                        *v = value.clone();
+                                    }
+                                }
+                                if let (Some(_), Some(value)) = (child, value) {
                                     obj.add_field(alias, value);
                                 }
                             }
@@ -101,9 +149,8 @@ macro_rules! wundergraph_value_impl {
                             }
                         }
                     )*
-                    Ok(objs)
+                    Ok((objs, column_values))
                 }
-
             }
 
 
@@ -0,0 +1,225 @@
+//! This module contains helper types to order entities by fields of
+//! related entities
+//!
+//! For each `HasOne` field named `field` the order enum of an entity contains
+//! a `{field}_{related_field}` value for each field of the related entity
+//! that is backed by a column. Those values are compiled to a correlated
+//! subquery selecting the related column.
+//!
+//! For each `HasMany` field named `field` the order enum contains a
+//! `{field}_COUNT` value, compiled to a correlated subquery counting the
+//! related entities.
+
+use super::offset::ApplyOffset;
+use super::order::{order_by_expression, Nulls, Order, OrderClauses};
+use super::LoadingHandler;
+use crate::query_builder::selection::fields::WundergraphFieldList;
+use crate::query_builder::selection::filter::count_filter::RelatedCount;
+use diesel::associations::HasTable;
+use diesel::backend::Backend;
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::sql_types::SingleValue;
+use diesel::{Column, QueryResult, QuerySource, Table};
+use std::any::TypeId;
+use std::marker::PhantomData;
+
+/// Selects a column of the entity referenced by the current row
+///
+/// Compiles down to `(SELECT other.column FROM other WHERE other.id = fk)`
+#[derive(Debug)]
+pub struct RelatedColumn<FK, PK, C> {
+    p: PhantomData<(FK, PK, C)>,
+}
+
+impl<FK, PK, C> Default for RelatedColumn<FK, PK, C> {
+    fn default() -> Self {
+        Self { p: PhantomData }
+    }
+}
+
+impl<FK, PK, C> Clone for RelatedColumn<FK, PK, C> {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl<FK, PK, C> Expression for RelatedColumn<FK, PK, C>
+where
+    C: Expression,
+{
+    type SqlType = C::SqlType;
+}
+
+impl<FK, PK, C> NonAggregate for RelatedColumn<FK, PK, C> {}
+
+impl<FK, PK, C, QS> AppearsOnTable<QS> for RelatedColumn<FK, PK, C>
+where
+    FK: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+impl<FK, PK, C, QS> SelectableExpression<QS> for RelatedColumn<FK, PK, C>
+where
+    FK: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl<FK, PK, C> QueryId for RelatedColumn<FK, PK, C> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<FK, PK, C, DB> QueryFragment<DB> for RelatedColumn<FK, PK, C>
+where
+    DB: Backend,
+    FK: QueryFragment<DB> + Default,
+    PK: QueryFragment<DB> + Default,
+    C: Column + QueryFragment<DB> + Default,
+    C::Table: HasTable<Table = C::Table>,
+    <C::Table as QuerySource>::FromClause: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, DB>) -> QueryResult<()> {
+        pass.push_sql("(SELECT ");
+        C::default().walk_ast(pass.reborrow())?;
+        pass.push_sql(" FROM ");
+        C::Table::table().from_clause().walk_ast(pass.reborrow())?;
+        pass.push_sql(" WHERE ");
+        PK::default().walk_ast(pass.reborrow())?;
+        pass.push_sql(" = ");
+        FK::default().walk_ast(pass.reborrow())?;
+        pass.push_sql(")");
+        Ok(())
+    }
+}
+
+/// Build a order clause for a column of a related entity
+///
+/// Implemented for tuples of the columns of the related table
+pub trait BuildReferenceOrder<FK, PK, DB>
+where
+    FK: Column,
+{
+    /// Order by the column at the given index
+    ///
+    /// Returns `None` if there is no column at this index
+    fn build_reference_order(
+        index: usize,
+        order: Order,
+        nulls: Option<Nulls>,
+    ) -> Option<OrderClauses<FK::Table, DB>>;
+}
+
+macro_rules! impl_build_reference_order {
+    ($(
+        $Tuple:tt {
+            $(($idx:tt) -> $T:ident, $ST: ident, $TT: ident,) +
+        }
+    )+) => {
+        $(
+            impl<FK, PK, DB, $($T,)+> BuildReferenceOrder<FK, PK, DB> for ($($T,)+)
+            where FK: Column + SelectableExpression<FK::Table>,
+                  DB: Backend,
+                  $($T: Column,
+                  <$T as Expression>::SqlType: SingleValue,
+                  RelatedColumn<FK, PK, $T>: QueryFragment<DB> + 'static,)+
+            {
+                fn build_reference_order(
+                    index: usize,
+                    order: Order,
+                    nulls: Option<Nulls>,
+                ) -> Option<OrderClauses<FK::Table, DB>> {
+                    match index {
+                        $(
+                            $idx => {
+                                let column = RelatedColumn::<FK, PK, $T>::default();
+                                Some(order_by_expression(column, order, nulls))
+                            }
+                        )+
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+__diesel_for_each_tuple!(impl_build_reference_order);
+
+/// Order entities by the fields of an entity referenced by the
+/// foreign key column `FK`
+#[derive(Debug)]
+pub struct ReferenceOrder<FK, R, DB, Ctx>(PhantomData<(FK, R, DB, Ctx)>);
+
+impl<FK, R, DB, Ctx> ReferenceOrder<FK, R, DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+    FK: Column,
+    FK::Table: 'static,
+    R: LoadingHandler<DB, Ctx>,
+    R::Table: 'static,
+    R::Columns: BuildReferenceOrder<FK, <R::Table as Table>::PrimaryKey, DB>,
+{
+    // A correlated subquery on the same table would need a table alias
+    // to refer to the outer row
+    fn is_self_reference() -> bool {
+        TypeId::of::<FK::Table>() == TypeId::of::<R::Table>()
+    }
+
+    /// The names of all order columns for the `HasOne` field `field`
+    pub fn order_columns(field: &str) -> Vec<String> {
+        if Self::is_self_reference() {
+            return Vec::new();
+        }
+        (0..R::FieldList::TABLE_FIELD_COUNT)
+            .filter_map(|i| {
+                R::FieldList::map_table_field(i, |global| {
+                    format!("{}_{}", field, R::FIELD_NAMES[global])
+                })
+            })
+            .collect()
+    }
+
+    /// Build an order clause for the field `name` of the related entity
+    ///
+    /// Returns `None` if the related entity has no such field
+    pub fn build_order(
+        name: &str,
+        order: Order,
+        nulls: Option<Nulls>,
+    ) -> Option<OrderClauses<FK::Table, DB>> {
+        if Self::is_self_reference() {
+            return None;
+        }
+        let index = (0..R::FieldList::TABLE_FIELD_COUNT).find(|i| {
+            R::FieldList::map_table_field(*i, |global| R::FIELD_NAMES[global] == name)
+                .unwrap_or(false)
+        })?;
+        R::Columns::build_reference_order(index, order, nulls)
+    }
+}
+
+/// Order entities by the number of related entities referencing them
+/// through the foreign key column `FK` of a `HasMany` relation
+#[derive(Debug)]
+pub struct CountOrder<PK, FK, DB>(PhantomData<(PK, FK, DB)>);
+
+impl<PK, FK, DB> CountOrder<PK, FK, DB>
+where
+    DB: Backend,
+    PK: Column + SelectableExpression<PK::Table> + 'static,
+    FK: 'static,
+    RelatedCount<PK, FK>: QueryFragment<DB>,
+{
+    /// The name of the order column for the `HasMany` field `field`
+    pub fn order_column(field: &str) -> String {
+        format!("{}_COUNT", field)
+    }
+
+    /// Build an order clause for the number of related entities
+    pub fn build_order(order: Order, nulls: Option<Nulls>) -> OrderClauses<PK::Table, DB> {
+        order_by_expression(RelatedCount::<PK, FK>::default(), order, nulls)
+    }
+}
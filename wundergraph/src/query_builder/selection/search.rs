@@ -0,0 +1,316 @@
+//! This module contains helper types to support full text search
+//! on the text columns of an entity
+//!
+//! Full text search is opt-in. `#[derive(WundergraphEntity)]` enables it
+//! for entities marked with `#[wundergraph(full_text_search = "fts_table")]`.
+//! Those entities get a `search` argument next to their `filter` argument
+//! and a `RELEVANCE` value in their order enum. The search covers all
+//! `String` fields of the entity.
+//!
+//! On postgres full text search is implemented by using `to_tsvector`,
+//! `plainto_tsquery` and `ts_rank` directly on the searched columns.
+//!
+//! On sqlite each searched table needs to be shadowed by a
+//! [FTS5](https://www.sqlite.org/fts5.html) virtual table that uses the
+//! same `rowid`s as the original table, for example by using an external
+//! content table:
+//!
+//! ```sql
+//! CREATE VIRTUAL TABLE heros_fts USING fts5(
+//!     name,
+//!     content='heros',
+//!     content_rowid='id'
+//! );
+//! ```
+//!
+//! The name of this virtual table is provided by the
+//! [`FullTextSearch`](trait.FullTextSearch.html) trait. All columns of the
+//! virtual table are searched.
+//!
+//! On mysql full text search is implemented by using
+//! `MATCH (columns) AGAINST (query IN NATURAL LANGUAGE MODE)`, which requires
+//! a `FULLTEXT` index over exactly the searched columns.
+
+#[cfg(feature = "sqlite")]
+use diesel::associations::HasTable;
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::sql_types::{Bool, Float};
+#[cfg(feature = "sqlite")]
+use diesel::QuerySource;
+use diesel::{QueryResult, Table};
+use std::marker::PhantomData;
+
+/// Names the FTS5 virtual table used to search a table on sqlite
+///
+/// `#[derive(WundergraphEntity)]` implements this trait for the table of
+/// each entity marked with `#[wundergraph(full_text_search = "fts_table")]`.
+pub trait FullTextSearch: Table {
+    /// The name of the FTS5 virtual table that indexes this table
+    const FTS_TABLE: &'static str;
+}
+
+/// Checks if a row of the table `T` matches a given full text search query
+///
+/// `C` is a tuple of the searched text columns
+#[derive(Debug)]
+pub struct FullTextMatch<T, C> {
+    // sqlite searches all columns of the virtual table instead
+    #[cfg(any(feature = "postgres", feature = "mysql"))]
+    columns: C,
+    query: String,
+    p: PhantomData<(T, C)>,
+}
+
+impl<T, C> FullTextMatch<T, C> {
+    /// Search the given columns for `query`
+    #[cfg_attr(
+        not(any(feature = "postgres", feature = "mysql")),
+        allow(clippy::needless_pass_by_value)
+    )]
+    pub fn new(columns: C, query: String) -> Self {
+        #[cfg(not(any(feature = "postgres", feature = "mysql")))]
+        let _ = columns;
+        Self {
+            #[cfg(any(feature = "postgres", feature = "mysql"))]
+            columns,
+            query,
+            p: PhantomData,
+        }
+    }
+}
+
+/// The relevance of a row of the table `T` for a given full text search query
+///
+/// Larger values indicate more relevant rows
+#[derive(Debug)]
+pub struct Relevance<T, C> {
+    // sqlite searches all columns of the virtual table instead
+    #[cfg(any(feature = "postgres", feature = "mysql"))]
+    columns: C,
+    query: String,
+    p: PhantomData<(T, C)>,
+}
+
+impl<T, C> Relevance<T, C> {
+    /// Rank the given columns for `query`
+    #[cfg_attr(
+        not(any(feature = "postgres", feature = "mysql")),
+        allow(clippy::needless_pass_by_value)
+    )]
+    pub fn new(columns: C, query: String) -> Self {
+        #[cfg(not(any(feature = "postgres", feature = "mysql")))]
+        let _ = columns;
+        Self {
+            #[cfg(any(feature = "postgres", feature = "mysql"))]
+            columns,
+            query,
+            p: PhantomData,
+        }
+    }
+}
+
+macro_rules! impl_search_expression {
+    ($name: ident, $sql_type: ty) => {
+        impl<T, C> Clone for $name<T, C>
+        where
+            C: Clone,
+        {
+            fn clone(&self) -> Self {
+                Self {
+                    #[cfg(any(feature = "postgres", feature = "mysql"))]
+                    columns: self.columns.clone(),
+                    query: self.query.clone(),
+                    p: PhantomData,
+                }
+            }
+        }
+
+        impl<T, C> Expression for $name<T, C>
+        where
+            C: Expression,
+        {
+            type SqlType = $sql_type;
+        }
+
+        impl<T, C> NonAggregate for $name<T, C> where C: NonAggregate {}
+
+        impl<T, C, QS> AppearsOnTable<QS> for $name<T, C>
+        where
+            C: AppearsOnTable<QS>,
+            Self: Expression,
+        {
+        }
+
+        impl<T, C, QS> SelectableExpression<QS> for $name<T, C>
+        where
+            C: SelectableExpression<QS>,
+            Self: AppearsOnTable<QS>,
+        {
+        }
+
+        impl<T, C> QueryId for $name<T, C> {
+            type QueryId = ();
+            const HAS_STATIC_QUERY_ID: bool = false;
+        }
+    };
+}
+
+impl_search_expression!(FullTextMatch, Bool);
+impl_search_expression!(Relevance, Float);
+
+/// Writes `to_tsvector(concat_ws(' ', columns))` for postgres
+#[cfg(feature = "postgres")]
+fn walk_pg_tsvector<C>(columns: &C, mut pass: AstPass<'_, diesel::pg::Pg>) -> QueryResult<()>
+where
+    C: QueryFragment<diesel::pg::Pg>,
+{
+    pass.push_sql("to_tsvector(concat_ws(' ', ");
+    columns.walk_ast(pass.reborrow())?;
+    pass.push_sql("))");
+    Ok(())
+}
+
+#[cfg(feature = "postgres")]
+impl<T, C> QueryFragment<diesel::pg::Pg> for FullTextMatch<T, C>
+where
+    C: QueryFragment<diesel::pg::Pg>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::pg::Pg>) -> QueryResult<()> {
+        walk_pg_tsvector(&self.columns, pass.reborrow())?;
+        pass.push_sql(" @@ plainto_tsquery(");
+        pass.push_bind_param::<diesel::sql_types::Text, _>(&self.query)?;
+        pass.push_sql(")");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<T, C> QueryFragment<diesel::pg::Pg> for Relevance<T, C>
+where
+    C: QueryFragment<diesel::pg::Pg>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::pg::Pg>) -> QueryResult<()> {
+        pass.push_sql("ts_rank(");
+        walk_pg_tsvector(&self.columns, pass.reborrow())?;
+        pass.push_sql(", plainto_tsquery(");
+        pass.push_bind_param::<diesel::sql_types::Text, _>(&self.query)?;
+        pass.push_sql("))");
+        Ok(())
+    }
+}
+
+/// Writes `MATCH (columns) AGAINST (? IN NATURAL LANGUAGE MODE)` for mysql
+#[cfg(feature = "mysql")]
+fn walk_mysql_match<C>(
+    columns: &C,
+    query: &str,
+    mut pass: AstPass<'_, diesel::mysql::Mysql>,
+) -> QueryResult<()>
+where
+    C: QueryFragment<diesel::mysql::Mysql>,
+{
+    pass.push_sql("MATCH (");
+    columns.walk_ast(pass.reborrow())?;
+    pass.push_sql(") AGAINST (");
+    pass.push_bind_param::<diesel::sql_types::Text, _>(&query)?;
+    pass.push_sql(" IN NATURAL LANGUAGE MODE)");
+    Ok(())
+}
+
+#[cfg(feature = "mysql")]
+impl<T, C> QueryFragment<diesel::mysql::Mysql> for FullTextMatch<T, C>
+where
+    C: QueryFragment<diesel::mysql::Mysql>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::mysql::Mysql>) -> QueryResult<()> {
+        walk_mysql_match(&self.columns, &self.query, pass.reborrow())?;
+        pass.push_sql(" > 0");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<T, C> QueryFragment<diesel::mysql::Mysql> for Relevance<T, C>
+where
+    C: QueryFragment<diesel::mysql::Mysql>,
+{
+    fn walk_ast(&self, pass: AstPass<'_, diesel::mysql::Mysql>) -> QueryResult<()> {
+        walk_mysql_match(&self.columns, &self.query, pass)
+    }
+}
+
+/// Converts a plain text query into a FTS5 query string
+///
+/// Each whitespace separated word is quoted, so that the query
+/// matches all rows containing all words, similar to `plainto_tsquery`
+#[cfg(feature = "sqlite")]
+fn fts5_query(query: &str) -> Option<String> {
+    let words = query
+        .split_whitespace()
+        .map(|w| format!("\"{}\"", w.replace('"', "\"\"")))
+        .collect::<Vec<_>>();
+    if words.is_empty() {
+        None
+    } else {
+        Some(words.join(" "))
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<T, C> QueryFragment<diesel::sqlite::Sqlite> for FullTextMatch<T, C>
+where
+    T: FullTextSearch + HasTable<Table = T>,
+    <T as QuerySource>::FromClause: QueryFragment<diesel::sqlite::Sqlite>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::sqlite::Sqlite>) -> QueryResult<()> {
+        let query = match fts5_query(&self.query) {
+            Some(query) => query,
+            None => {
+                pass.push_sql("0");
+                return Ok(());
+            }
+        };
+        T::table().from_clause().walk_ast(pass.reborrow())?;
+        pass.push_sql(".rowid IN (SELECT rowid FROM ");
+        pass.push_identifier(T::FTS_TABLE)?;
+        pass.push_sql(" WHERE ");
+        pass.push_identifier(T::FTS_TABLE)?;
+        pass.push_sql(" MATCH ");
+        pass.push_bind_param::<diesel::sql_types::Text, _>(&query)?;
+        pass.push_sql(")");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<T, C> QueryFragment<diesel::sqlite::Sqlite> for Relevance<T, C>
+where
+    T: FullTextSearch + HasTable<Table = T>,
+    <T as QuerySource>::FromClause: QueryFragment<diesel::sqlite::Sqlite>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::sqlite::Sqlite>) -> QueryResult<()> {
+        let query = match fts5_query(&self.query) {
+            Some(query) => query,
+            None => {
+                pass.push_sql("NULL");
+                return Ok(());
+            }
+        };
+        // bm25 returns smaller values for better matches
+        pass.push_sql("(SELECT -bm25(");
+        pass.push_identifier(T::FTS_TABLE)?;
+        pass.push_sql(") FROM ");
+        pass.push_identifier(T::FTS_TABLE)?;
+        pass.push_sql(" WHERE ");
+        pass.push_identifier(T::FTS_TABLE)?;
+        pass.push_sql(".rowid = ");
+        T::table().from_clause().walk_ast(pass.reborrow())?;
+        pass.push_sql(".rowid AND ");
+        pass.push_identifier(T::FTS_TABLE)?;
+        pass.push_sql(" MATCH ");
+        pass.push_bind_param::<diesel::sql_types::Text, _>(&query)?;
+        pass.push_sql(")");
+        Ok(())
+    }
+}
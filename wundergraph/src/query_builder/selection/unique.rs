@@ -0,0 +1,91 @@
+//! Lookup of single entities by fields with unique values
+//!
+//! `query_object!` generates a `{Entity}By{Field}(field: ...)` field for
+//! each field listed in
+//! [`LoadingHandler::UNIQUE_FIELDS`](../trait.LoadingHandler.html#associatedconstant.UNIQUE_FIELDS),
+//! for example `HeroByHeroName(heroName: "Luke Skywalker")`. The argument
+//! has the same type as the field, the entity is loaded by
+//! [`LoadingHandler::load_by_unique_field`](../trait.LoadingHandler.html#method.load_by_unique_field)
+
+use crate::graphql_type::GraphqlWrapper;
+use crate::query_builder::selection::offset::ApplyOffset;
+use crate::query_builder::selection::LoadingHandler;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use juniper::meta::{self, Argument};
+use juniper::{GraphQLType, Registry, Type};
+
+/// The name of the lookup field of `entity` by the unique field `field`
+pub fn lookup_field_name(entity: &str, field: &str) -> String {
+    let mut chars = field.chars();
+    let first = chars
+        .next()
+        .map(|c| c.to_uppercase().collect::<String>())
+        .unwrap_or_default();
+    format!("{}By{}{}", entity, first, chars.as_str())
+}
+
+/// Find the unique field of `L` queried by the lookup field `lookup`
+pub fn unique_field<L, DB, Ctx>(entity: &str, lookup: &str) -> Option<&'static str>
+where
+    DB: Backend + ApplyOffset + 'static,
+    L: LoadingHandler<DB, Ctx>,
+{
+    L::UNIQUE_FIELDS
+        .iter()
+        .find(|field| lookup_field_name(entity, field) == lookup)
+        .cloned()
+}
+
+/// Register a lookup field for each unique field of `L`
+///
+/// The argument type is the type of the corresponding field of the
+/// entity object, made non nullable.
+pub fn register_unique_lookups<'r, L, DB, Ctx>(
+    entity: &str,
+    registry: &mut Registry<'r, WundergraphScalarValue>,
+) -> Vec<meta::Field<'r, WundergraphScalarValue>>
+where
+    DB: Backend + ApplyOffset + 'static,
+    L: LoadingHandler<DB, Ctx>,
+    GraphqlWrapper<L, DB, Ctx>: GraphQLType<WundergraphScalarValue, TypeInfo = ()>,
+{
+    if L::UNIQUE_FIELDS.is_empty() {
+        return Vec::new();
+    }
+    let type_name = registry
+        .get_type::<GraphqlWrapper<L, DB, Ctx>>(&())
+        .innermost_name()
+        .to_owned();
+    // The argument types are collected before registering the lookup
+    // fields, as those need mutable access to the registry
+    let fields = match registry.types.get(&type_name) {
+        Some(meta::MetaType::Object(meta::ObjectMeta { ref fields, .. })) => fields,
+        _ => return Vec::new(),
+    };
+    let arguments: Vec<(&str, Type<'r>)> = L::UNIQUE_FIELDS
+        .iter()
+        .filter_map(|field| {
+            let field_type = fields.iter().find(|f| f.name == *field)?.field_type.clone();
+            let field_type = match field_type {
+                Type::Named(name) => Type::NonNullNamed(name),
+                Type::List(inner) => Type::NonNullList(inner),
+                t => t,
+            };
+            Some((*field, field_type))
+        })
+        .collect();
+    arguments
+        .into_iter()
+        .map(|(field, field_type)| {
+            registry
+                .field::<Option<GraphqlWrapper<L, DB, Ctx>>>(&lookup_field_name(entity, field), &())
+                .description(&format!(
+                    "Load a single {} by its unique `{}` field",
+                    L::TYPE_NAME,
+                    field
+                ))
+                .argument(Argument::new(field, field_type))
+        })
+        .collect()
+}
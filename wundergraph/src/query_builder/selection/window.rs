@@ -0,0 +1,216 @@
+//! This module contains a helper type to apply `limit` and `offset`
+//! to the entities of each parent of a `HasMany` relation separately
+//!
+//! All related entities of a `HasMany` field are loaded with a single
+//! query. A plain limit clause would restrict the overall number of loaded
+//! entities, so the entities are numbered per parent with
+//! `ROW_NUMBER() OVER (PARTITION BY fk ORDER BY ...)` instead and only the
+//! rows inside of the requested window are selected.
+
+use super::order::OrderClauses;
+use diesel::backend::Backend;
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use diesel::query_builder::{AstPass, BoxedSelectStatement, QueryFragment, QueryId};
+use diesel::query_dsl::methods::SelectDsl;
+use diesel::serialize::ToSql;
+use diesel::sql_types::{BigInt, Bool, HasSqlType};
+use diesel::{Column, QueryResult, Table};
+
+const WINDOW_ALIAS: &str = "__wundergraph_window";
+const ROW_NUMBER: &str = "__wundergraph_row_number";
+
+/// The names of a column or of a tuple of columns
+///
+/// Used to reference the primary key columns of a table from outside
+/// of a subquery. `K` only distinguishes the implementation for single
+/// columns from the one for tuples and is always inferred.
+pub trait ColumnNames<K> {
+    /// The unqualified names of the columns
+    const NAMES: &'static [&'static str];
+}
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct SingleColumn;
+
+impl<C> ColumnNames<SingleColumn> for C
+where
+    C: Column,
+{
+    const NAMES: &'static [&'static str] = &[C::NAME];
+}
+
+macro_rules! column_names_impl {
+    ($(
+        $Tuple:tt {
+            $(($idx: tt) -> $T:ident, $ST: ident, $TT: ident,)+
+        }
+    )+) => {
+        $(
+            impl<$($T,)+> ColumnNames<($($T,)+)> for ($($T,)+)
+            where
+                $($T: Column,)+
+            {
+                const NAMES: &'static [&'static str] = &[$($T::NAME,)+];
+            }
+        )+
+    }
+}
+
+__diesel_for_each_tuple!(column_names_impl);
+
+/// Selects the primary key of the table `T` together with the position of
+/// each row inside of its partition
+///
+/// Compiles down to
+/// ```sql
+/// pk, ROW_NUMBER() OVER (PARTITION BY fk ORDER BY order, pk) AS __wundergraph_row_number
+/// ```
+struct RowNumber<'a, T, DB> {
+    table: T,
+    partition: Box<dyn QueryFragment<DB> + 'a>,
+    order: OrderClauses<T, DB>,
+}
+
+impl<'a, T, DB> Expression for RowNumber<'a, T, DB> {
+    type SqlType = BigInt;
+}
+
+impl<'a, T, DB> AppearsOnTable<T> for RowNumber<'a, T, DB> {}
+
+impl<'a, T, DB> SelectableExpression<T> for RowNumber<'a, T, DB> {}
+
+impl<'a, T, DB> QueryFragment<DB> for RowNumber<'a, T, DB>
+where
+    DB: Backend,
+    T: Table,
+    T::PrimaryKey: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, DB>) -> QueryResult<()> {
+        let primary_key = self.table.primary_key();
+        primary_key.walk_ast(pass.reborrow())?;
+        pass.push_sql(", ROW_NUMBER() OVER (PARTITION BY ");
+        self.partition.walk_ast(pass.reborrow())?;
+        pass.push_sql(" ORDER BY ");
+        for o in &self.order {
+            o.walk_ast(pass.reborrow())?;
+            pass.push_sql(", ");
+        }
+        primary_key.walk_ast(pass.reborrow())?;
+        pass.push_sql(") AS ");
+        pass.push_identifier(ROW_NUMBER)
+    }
+}
+
+/// Selects the rows of a window of each partition of the table `T`
+///
+/// Compiles down to
+/// ```sql
+/// (pk) IN (SELECT __wundergraph_window.pk FROM (
+///     SELECT pk, ROW_NUMBER() OVER (PARTITION BY fk ORDER BY order, pk) AS __wundergraph_row_number
+///     FROM table WHERE filter
+/// ) AS __wundergraph_window
+/// WHERE __wundergraph_row_number > offset AND __wundergraph_row_number <= offset + limit)
+/// ```
+/// Only the rows returned by the given query are numbered, so rows removed
+/// by a filter or by the `QueryModifier` of the context do not occupy a
+/// position of the window.
+#[allow(missing_debug_implementations)]
+pub struct PartitionWindow<'a, T, DB> {
+    table: T,
+    primary_key_names: &'static [&'static str],
+    numbered: Box<dyn QueryFragment<DB> + 'a>,
+    offset: i64,
+    limit: Option<i64>,
+}
+
+impl<'a, T, DB> PartitionWindow<'a, T, DB>
+where
+    DB: Backend + 'a,
+    T: Table + 'a,
+    T::PrimaryKey: QueryFragment<DB>,
+    T::FromClause: QueryFragment<DB>,
+{
+    /// Select the rows after the first `offset` rows of each partition given
+    /// by the `partition` expression, at most `limit` rows per partition
+    ///
+    /// The rows returned by `query` are numbered in the given order,
+    /// followed by the primary key, so that the numbering is stable
+    pub fn new<ST, P, K>(
+        table: T,
+        query: BoxedSelectStatement<'a, ST, T, DB>,
+        partition: P,
+        order: OrderClauses<T, DB>,
+        offset: i64,
+        limit: Option<i64>,
+    ) -> Self
+    where
+        T: Clone,
+        T::PrimaryKey: ColumnNames<K>,
+        P: QueryFragment<DB> + 'a,
+    {
+        let row_number = RowNumber {
+            table: table.clone(),
+            partition: Box::new(partition),
+            order,
+        };
+        Self {
+            table,
+            primary_key_names: <T::PrimaryKey as ColumnNames<K>>::NAMES,
+            numbered: Box::new(<_ as SelectDsl<_>>::select(query, row_number)),
+            offset,
+            limit,
+        }
+    }
+}
+
+impl<'a, T, DB> Expression for PartitionWindow<'a, T, DB> {
+    type SqlType = Bool;
+}
+
+impl<'a, T, DB> NonAggregate for PartitionWindow<'a, T, DB> {}
+
+impl<'a, T, DB> AppearsOnTable<T> for PartitionWindow<'a, T, DB> {}
+
+impl<'a, T, DB> QueryId for PartitionWindow<'a, T, DB> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<'a, T, DB> QueryFragment<DB> for PartitionWindow<'a, T, DB>
+where
+    DB: Backend + HasSqlType<BigInt>,
+    T: Table,
+    T::PrimaryKey: QueryFragment<DB>,
+    i64: ToSql<BigInt, DB>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, DB>) -> QueryResult<()> {
+        pass.push_sql("(");
+        self.table.primary_key().walk_ast(pass.reborrow())?;
+        pass.push_sql(") IN (SELECT ");
+        for (i, name) in self.primary_key_names.iter().enumerate() {
+            if i > 0 {
+                pass.push_sql(", ");
+            }
+            pass.push_identifier(WINDOW_ALIAS)?;
+            pass.push_sql(".");
+            pass.push_identifier(name)?;
+        }
+        pass.push_sql(" FROM (");
+        self.numbered.walk_ast(pass.reborrow())?;
+        pass.push_sql(") AS ");
+        pass.push_identifier(WINDOW_ALIAS)?;
+        pass.push_sql(" WHERE ");
+        pass.push_identifier(ROW_NUMBER)?;
+        pass.push_sql(" > ");
+        pass.push_bind_param::<BigInt, _>(&self.offset)?;
+        if let Some(limit) = self.limit {
+            pass.push_sql(" AND ");
+            pass.push_identifier(ROW_NUMBER)?;
+            pass.push_sql(" <= ");
+            pass.push_bind_param::<BigInt, _>(&(self.offset + limit))?;
+        }
+        pass.push_sql(")");
+        Ok(())
+    }
+}
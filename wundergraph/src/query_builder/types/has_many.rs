@@ -20,4 +20,14 @@ where
     ) -> meta::Field<'r, WundergraphScalarValue> {
         T::register_arguments(registry, field)
     }
+
+    fn register_additional_fields<'r>(
+        name: &str,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<meta::Field<'r, WundergraphScalarValue>> {
+        let field = registry
+            .field::<i64>(&format!("{}Count", name), &())
+            .description("Number of entities referenced by this field");
+        vec![field]
+    }
 }
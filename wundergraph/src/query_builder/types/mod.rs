@@ -12,3 +12,6 @@ pub use self::has_many::HasMany;
 pub use self::has_one::HasOne;
 pub use self::placeholder::PlaceHolder;
 pub use self::wundergraph_value::WundergraphValue;
+
+#[cfg(all(feature = "serde_json", feature = "postgres"))]
+pub use crate::third_party_integrations::serde_json::Json;
@@ -1,6 +1,7 @@
 use crate::juniper_ext::{FromLookAheadValue, Nameable};
 use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
 use crate::query_builder::selection::filter::filter_value::FilterValue;
+use crate::query_builder::selection::filter::range_filter::RangeFilter;
 use crate::query_builder::selection::filter::FilterOption;
 use crate::query_builder::types::{PlaceHolder, WundergraphValue};
 use crate::scalar::WundergraphScalarValue;
@@ -95,7 +96,7 @@ impl WundergraphValue for NaiveDate {
 
 impl<C> FilterValue<C> for NaiveDateTime {
     type RawValue = Self;
-    type AdditionalFilter = ();
+    type AdditionalFilter = RangeFilter<Self, C>;
 }
 
 impl<O, C> FilterValue<C> for DateTime<O>
@@ -106,12 +107,12 @@ where
         + FromLookAheadValue,
 {
     type RawValue = Self;
-    type AdditionalFilter = ();
+    type AdditionalFilter = RangeFilter<Self, C>;
 }
 
 impl<C> FilterValue<C> for NaiveDate {
     type RawValue = Self;
-    type AdditionalFilter = ();
+    type AdditionalFilter = RangeFilter<Self, C>;
 }
 
 impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for NaiveDateTime {
@@ -1,4 +1,8 @@
 #[cfg(feature = "chrono")]
 mod chrono;
+#[cfg(all(feature = "regex", feature = "sqlite"))]
+pub(crate) mod regex;
+#[cfg(all(feature = "serde_json", feature = "postgres"))]
+pub(crate) mod serde_json;
 #[cfg(all(feature = "uuid", feature = "postgres"))]
 mod uuid;
@@ -0,0 +1,32 @@
+use diesel::sql_types::{Bool, Text};
+use diesel::sqlite::SqliteConnection;
+use diesel::QueryResult;
+use regex_internal::Regex;
+use std::sync::Mutex;
+
+sql_function!(fn regexp(pattern: Text, value: Text) -> Bool);
+
+/// Register a `REGEXP` sql function backed by the `regex` crate
+/// on the given `SqliteConnection`
+///
+/// Sqlite does not ship an implementation of the `REGEXP` operator,
+/// so this function needs to be called for each connection before the
+/// `matches` or `imatches` string filters are used. Invalid patterns
+/// never match.
+pub fn register_regexp_function(conn: &SqliteConnection) -> QueryResult<()> {
+    let cache: Mutex<Option<(String, Option<Regex>)>> = Mutex::new(None);
+    regexp::register_impl(conn, move |pattern: String, value: String| {
+        let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+        match *cache {
+            Some((ref p, _)) if *p == pattern => {}
+            _ => {
+                let regex = Regex::new(&pattern).ok();
+                *cache = Some((pattern, regex));
+            }
+        }
+        cache
+            .as_ref()
+            .and_then(|(_, r)| r.as_ref())
+            .map_or(false, |r| r.is_match(&value))
+    })
+}
@@ -0,0 +1,238 @@
+use crate::juniper_ext::Nameable;
+use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
+use crate::query_builder::selection::filter::json_filter::JsonFilter;
+use crate::query_builder::types::{PlaceHolder, WundergraphValue};
+use crate::scalar::WundergraphScalarValue;
+use diesel::deserialize::{self, FromSql};
+use diesel::pg::Pg;
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{Json as JsonType, Jsonb, Nullable};
+use juniper::parser::{ParseError, ScalarToken, Token};
+use juniper::{
+    graphql_scalar, InputValue, LookAheadValue, Object, ParseScalarResult, ParseScalarValue,
+    Value as GraphQLValue,
+};
+use serde_json_internal::{Map, Number, Value};
+use std::convert::TryFrom;
+use std::io::Write;
+
+/// A wrapper type for columns of the postgres `json` sql type
+///
+/// `serde_json::Value` is mapped to `jsonb` columns, use this type
+/// for entity fields backed by a `json` column instead. Both types
+/// are represented by the same `JSON` GraphQL scalar.
+#[derive(Debug, Clone, PartialEq, AsExpression, FromSqlRow)]
+#[sql_type = "JsonType"]
+pub struct Json(pub Value);
+
+impl From<Value> for Json {
+    fn from(v: Value) -> Self {
+        Json(v)
+    }
+}
+
+impl From<Json> for Value {
+    fn from(v: Json) -> Self {
+        v.0
+    }
+}
+
+impl FromSql<JsonType, Pg> for Json {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        <Value as FromSql<JsonType, Pg>>::from_sql(bytes).map(Json)
+    }
+}
+
+impl ToSql<JsonType, Pg> for Json {
+    fn to_sql<W: Write>(&self, out: &mut Output<'_, W, Pg>) -> serialize::Result {
+        <Value as ToSql<JsonType, Pg>>::to_sql(&self.0, out)
+    }
+}
+
+fn json_to_graphql(value: &Value) -> GraphQLValue<WundergraphScalarValue> {
+    match *value {
+        Value::Null => GraphQLValue::null(),
+        Value::Bool(b) => GraphQLValue::scalar(b),
+        Value::Number(ref n) => {
+            if let Some(i) = n.as_i64() {
+                i32::try_from(i).map_or_else(|_| GraphQLValue::scalar(i), GraphQLValue::scalar)
+            } else {
+                GraphQLValue::scalar(n.as_f64().unwrap_or_default())
+            }
+        }
+        Value::String(ref s) => GraphQLValue::scalar(s.clone()),
+        Value::Array(ref values) => {
+            GraphQLValue::list(values.iter().map(json_to_graphql).collect())
+        }
+        Value::Object(ref map) => {
+            let mut obj = Object::with_capacity(map.len());
+            for (k, v) in map {
+                obj.add_field(k.clone(), json_to_graphql(v));
+            }
+            GraphQLValue::object(obj)
+        }
+    }
+}
+
+fn scalar_to_json(value: &WundergraphScalarValue) -> Option<Value> {
+    Some(match *value {
+        WundergraphScalarValue::SmallInt(i) => Value::from(i),
+        WundergraphScalarValue::Int(i) => Value::from(i),
+        WundergraphScalarValue::BigInt(i) => Value::from(i),
+        WundergraphScalarValue::Float(f) => Value::Number(Number::from_f64(f64::from(f))?),
+        WundergraphScalarValue::Double(f) => Value::Number(Number::from_f64(f)?),
+        WundergraphScalarValue::String(ref s) => Value::String(s.clone()),
+        WundergraphScalarValue::Boolean(b) => Value::Bool(b),
+    })
+}
+
+fn input_value_to_json(value: &InputValue<WundergraphScalarValue>) -> Option<Value> {
+    match *value {
+        InputValue::Null => Some(Value::Null),
+        InputValue::Scalar(ref s) => scalar_to_json(s),
+        InputValue::List(ref values) => values
+            .iter()
+            .map(|v| input_value_to_json(&v.item))
+            .collect::<Option<_>>()
+            .map(Value::Array),
+        InputValue::Object(ref fields) => fields
+            .iter()
+            .map(|(k, v)| input_value_to_json(&v.item).map(|v| (k.item.clone(), v)))
+            .collect::<Option<Map<_, _>>>()
+            .map(Value::Object),
+        InputValue::Enum(_) | InputValue::Variable(_) => None,
+    }
+}
+
+/// Top level strings are interpreted as json encoded documents, as
+/// GraphQL does not allow object or list literals for scalar values
+fn json_from_input_value(value: &InputValue<WundergraphScalarValue>) -> Option<Value> {
+    match *value {
+        InputValue::Scalar(WundergraphScalarValue::String(ref s)) => {
+            serde_json_internal::from_str(s).ok()
+        }
+        ref v => input_value_to_json(v),
+    }
+}
+
+fn look_ahead_to_json(value: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Value> {
+    match *value {
+        LookAheadValue::Null => Some(Value::Null),
+        LookAheadValue::Scalar(s) => scalar_to_json(s),
+        LookAheadValue::List(ref values) => values
+            .iter()
+            .map(look_ahead_to_json)
+            .collect::<Option<_>>()
+            .map(Value::Array),
+        LookAheadValue::Object(ref fields) => fields
+            .iter()
+            .map(|(k, v)| look_ahead_to_json(v).map(|v| ((*k).to_owned(), v)))
+            .collect::<Option<Map<_, _>>>()
+            .map(Value::Object),
+        LookAheadValue::Enum(_) => None,
+    }
+}
+
+fn parse_json_token(value: ScalarToken<'_>) -> ParseScalarResult<'_, WundergraphScalarValue> {
+    match value {
+        ScalarToken::String(_) => {
+            <String as ParseScalarValue<WundergraphScalarValue>>::from_str(value)
+        }
+        ScalarToken::Int(v) => v
+            .parse::<i64>()
+            .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value)))
+            .map(Into::into),
+        ScalarToken::Float(v) => v
+            .parse::<f64>()
+            .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value)))
+            .map(Into::into),
+    }
+}
+
+graphql_scalar!(Value as "JSON" where Scalar = WundergraphScalarValue {
+    description: "An arbitrary json value. Input values are passed as json encoded strings"
+
+    resolve(&self) -> Value {
+        json_to_graphql(self)
+    }
+
+    from_input_value(v: &InputValue) -> Option<Value> {
+        json_from_input_value(v)
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        parse_json_token(value)
+    }
+});
+
+graphql_scalar!(Json as "JSON" where Scalar = WundergraphScalarValue {
+    description: "An arbitrary json value. Input values are passed as json encoded strings"
+
+    resolve(&self) -> Value {
+        json_to_graphql(&self.0)
+    }
+
+    from_input_value(v: &InputValue) -> Option<Json> {
+        json_from_input_value(v).map(Json)
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        parse_json_token(value)
+    }
+});
+
+impl Nameable for Value {
+    fn name() -> String {
+        String::from("JSON")
+    }
+}
+
+impl Nameable for Json {
+    fn name() -> String {
+        String::from("JSON")
+    }
+}
+
+/// Converts a look ahead value into a json value
+///
+/// Top level strings are interpreted as json encoded documents, like in
+/// `json_from_input_value`. `Value` and `Json` do not implement
+/// `FromLookAheadValue`, as that would conflict with the `AsColumnFilter`
+/// implementations for `Option<Value>` and `Option<Json>`
+pub(crate) fn json_from_look_ahead(
+    v: &LookAheadValue<'_, WundergraphScalarValue>,
+) -> Option<Value> {
+    match *v {
+        LookAheadValue::Null => None,
+        LookAheadValue::Scalar(WundergraphScalarValue::String(ref s)) => {
+            serde_json_internal::from_str(s).ok()
+        }
+        ref v => look_ahead_to_json(v),
+    }
+}
+
+impl WundergraphValue for Value {
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = Nullable<Jsonb>;
+}
+
+impl WundergraphValue for Json {
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = Nullable<JsonType>;
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for Value {
+    type Filter = JsonFilter<C>;
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for Option<Value> {
+    type Filter = JsonFilter<C>;
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for Json {
+    type Filter = JsonFilter<C>;
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for Option<Json> {
+    type Filter = JsonFilter<C>;
+}
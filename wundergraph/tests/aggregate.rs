@@ -0,0 +1,201 @@
+use crate::helper::*;
+use wundergraph_example::MyContext;
+
+#[test]
+fn aggregate() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HerosAggregate(filter: {home_world: {name: {eq: "Tatooine"}}}) {
+        count
+    }
+    all: HerosAggregate {
+        total: count
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HerosAggregate": {
+          "count": 2
+        },
+        "all": {
+          "total": 5
+        }
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn aggregate_without_matches() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HerosAggregate(filter: {heroName: {eq: "Yoda"}}) {
+        __typename
+        count
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HerosAggregate": {
+          "__typename": "HeroAggregate",
+          "count": 0
+        }
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn aggregate_of_primary_key() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HerosAggregate {
+        sum { id }
+    }
+}
+",
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn aggregate_groups() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HerosAggregate(filter: {home_world: {is_null: false}}) {
+        count
+        groups(groupBy: [home_world, species]) {
+            __typename
+            key {
+                species { name }
+                home_world { name }
+            }
+            count
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HerosAggregate": {
+          "count": 3,
+          "groups": [
+            {
+              "__typename": "HeroAggregateGroup",
+              "count": 2,
+              "key": {
+                "home_world": {
+                  "name": "Tatooine"
+                },
+                "species": {
+                  "name": "Human"
+                }
+              }
+            },
+            {
+              "__typename": "HeroAggregateGroup",
+              "count": 1,
+              "key": {
+                "home_world": {
+                  "name": "Alderaan"
+                },
+                "species": {
+                  "name": "Human"
+                }
+              }
+            }
+          ]
+        }
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn aggregate_groups_with_ungrouped_field() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HerosAggregate {
+        groups(groupBy: [species]) {
+            key {
+                heroName
+            }
+            count
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      null,
+      [
+        {
+          "locations": [
+            {
+              "column": 5,
+              "line": 3
+            }
+          ],
+          "message": "Field `heroName` is not part of the groupBy argument",
+          "path": [
+            "HerosAggregate"
+          ]
+        }
+      ]
+    ]
+    "###
+    );
+}
@@ -0,0 +1,862 @@
+use crate::helper::*;
+use wundergraph_example::MyContext;
+
+#[test]
+fn connection_first() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HerosConnection(first: 2) {
+        edges {
+            cursor
+            node {
+                heroName
+            }
+        }
+        pageInfo {
+            hasNextPage
+            hasPreviousPage
+            startCursor
+            endCursor
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HerosConnection": {
+          "edges": [
+            {
+              "cursor": "b2Zmc2V0OjA=",
+              "node": {
+                "heroName": "Luke Skywalker"
+              }
+            },
+            {
+              "cursor": "b2Zmc2V0OjE=",
+              "node": {
+                "heroName": "Darth Vader"
+              }
+            }
+          ],
+          "pageInfo": {
+            "endCursor": "b2Zmc2V0OjE=",
+            "hasNextPage": true,
+            "hasPreviousPage": false,
+            "startCursor": "b2Zmc2V0OjA="
+          }
+        }
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn connection_first_after() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HerosConnection(first: 2, after: "b2Zmc2V0OjE=") {
+        edges {
+            cursor
+            node {
+                heroName
+            }
+        }
+        pageInfo {
+            hasNextPage
+            hasPreviousPage
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HerosConnection": {
+          "edges": [
+            {
+              "cursor": "b2Zmc2V0OjI=",
+              "node": {
+                "heroName": "Han Solo"
+              }
+            },
+            {
+              "cursor": "b2Zmc2V0OjM=",
+              "node": {
+                "heroName": "Leia Organa"
+              }
+            }
+          ],
+          "pageInfo": {
+            "hasNextPage": true,
+            "hasPreviousPage": true
+          }
+        }
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn connection_last() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HerosConnection(last: 2) {
+        edges {
+            cursor
+            node {
+                heroName
+            }
+        }
+        pageInfo {
+            hasNextPage
+            hasPreviousPage
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HerosConnection": {
+          "edges": [
+            {
+              "cursor": "b2Zmc2V0OjM=",
+              "node": {
+                "heroName": "Leia Organa"
+              }
+            },
+            {
+              "cursor": "b2Zmc2V0OjQ=",
+              "node": {
+                "heroName": "Wilhuff Tarkin"
+              }
+            }
+          ],
+          "pageInfo": {
+            "hasNextPage": false,
+            "hasPreviousPage": true
+          }
+        }
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn connection_last_before() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HerosConnection(last: 1, before: "b2Zmc2V0OjM=") {
+        edges {
+            cursor
+            node {
+                heroName
+            }
+        }
+        pageInfo {
+            hasNextPage
+            hasPreviousPage
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HerosConnection": {
+          "edges": [
+            {
+              "cursor": "b2Zmc2V0OjI=",
+              "node": {
+                "heroName": "Han Solo"
+              }
+            }
+          ],
+          "pageInfo": {
+            "hasNextPage": true,
+            "hasPreviousPage": true
+          }
+        }
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn connection_filter_order() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HerosConnection(
+        first: 2,
+        filter: {heroName: {not_eq: "Han Solo"}},
+        order: [{column: heroName, direction: DESC}]
+    ) {
+        edges {
+            node {
+                heroName
+                species {
+                    name
+                }
+            }
+        }
+        pageInfo {
+            hasNextPage
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HerosConnection": {
+          "edges": [
+            {
+              "node": {
+                "heroName": "Wilhuff Tarkin",
+                "species": {
+                  "name": "Human"
+                }
+              }
+            },
+            {
+              "node": {
+                "heroName": "Luke Skywalker",
+                "species": {
+                  "name": "Human"
+                }
+              }
+            }
+          ],
+          "pageInfo": {
+            "hasNextPage": true
+          }
+        }
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn connection_total_count() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HerosConnection(first: 1, filter: {heroName: {not_eq: "Han Solo"}}) {
+        totalCount
+        edges {
+            node {
+                heroName
+            }
+        }
+    }
+    HomeWorldsConnection(first: 1) {
+        totalCount
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HerosConnection": {
+          "edges": [
+            {
+              "node": {
+                "heroName": "Luke Skywalker"
+              }
+            }
+          ],
+          "totalCount": 4
+        },
+        "HomeWorldsConnection": {
+          "totalCount": 2
+        }
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn connection_limits() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap())
+        .with_default_limit(2)
+        .with_max_limit(3);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HerosConnection {
+        pageInfo {
+            hasNextPage
+        }
+        edges {
+            node {
+                heroName
+            }
+        }
+    }
+    HomeWorldsConnection {
+        edges {
+            node {
+                name
+            }
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HerosConnection": {
+          "edges": [
+            {
+              "node": {
+                "heroName": "Luke Skywalker"
+              }
+            },
+            {
+              "node": {
+                "heroName": "Darth Vader"
+              }
+            }
+          ],
+          "pageInfo": {
+            "hasNextPage": true
+          }
+        },
+        "HomeWorldsConnection": {
+          "edges": [
+            {
+              "node": {
+                "name": "Alderaan"
+              }
+            },
+            {
+              "node": {
+                "name": "Tatooine"
+              }
+            }
+          ]
+        }
+      },
+      []
+    ]
+    "###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HomeWorldsConnection(last: 4) {
+        edges {
+            node {
+                name
+            }
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      null,
+      [
+        {
+          "locations": [
+            {
+              "column": 5,
+              "line": 3
+            }
+          ],
+          "message": "Requested `last` of 4 exceeds the maximum of 3",
+          "path": [
+            "HomeWorldsConnection"
+          ]
+        }
+      ]
+    ]
+    "###
+    );
+}
+
+#[test]
+fn connection_invalid_cursor() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HerosConnection(first: 2, after: "foo") {
+        edges {
+            node {
+                heroName
+            }
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      null,
+      [
+        {
+          "locations": [
+            {
+              "column": 5,
+              "line": 3
+            }
+          ],
+          "message": "Invalid cursor passed to `after`",
+          "path": [
+            "HerosConnection"
+          ]
+        }
+      ]
+    ]
+    "###
+    );
+}
+
+#[test]
+fn connection_overflowing_cursor() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HerosConnection(first: 2, after: "b2Zmc2V0OjkyMjMzNzIwMzY4NTQ3NzU4MDc=") {
+        edges {
+            node {
+                heroName
+            }
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      null,
+      [
+        {
+          "locations": [
+            {
+              "column": 5,
+              "line": 3
+            }
+          ],
+          "message": "Invalid cursor passed to `after`",
+          "path": [
+            "HerosConnection"
+          ]
+        }
+      ]
+    ]
+    "###
+    );
+}
+
+#[test]
+fn keyset_connection_first() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HomeWorldsConnection(first: 1) {
+        edges {
+            cursor
+            node {
+                name
+            }
+        }
+        pageInfo {
+            hasNextPage
+            hasPreviousPage
+            startCursor
+            endCursor
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorldsConnection": {
+          "edges": [
+            {
+              "cursor": "a2V5c2V0Om5hbWU9c1FXeGtaWEpoWVc0PSxpZD1pMg==",
+              "node": {
+                "name": "Alderaan"
+              }
+            }
+          ],
+          "pageInfo": {
+            "endCursor": "a2V5c2V0Om5hbWU9c1FXeGtaWEpoWVc0PSxpZD1pMg==",
+            "hasNextPage": true,
+            "hasPreviousPage": false,
+            "startCursor": "a2V5c2V0Om5hbWU9c1FXeGtaWEpoWVc0PSxpZD1pMg=="
+          }
+        }
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn keyset_connection_first_after() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HomeWorldsConnection(first: 1, after: "a2V5c2V0Om5hbWU9c1FXeGtaWEpoWVc0PSxpZD1pMg==") {
+        edges {
+            cursor
+            node {
+                name
+            }
+        }
+        pageInfo {
+            hasNextPage
+            hasPreviousPage
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorldsConnection": {
+          "edges": [
+            {
+              "cursor": "a2V5c2V0Om5hbWU9c1ZHRjBiMjlwYm1VPSxpZD1pMQ==",
+              "node": {
+                "name": "Tatooine"
+              }
+            }
+          ],
+          "pageInfo": {
+            "hasNextPage": false,
+            "hasPreviousPage": false
+          }
+        }
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn keyset_connection_last_before() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    last: HomeWorldsConnection(last: 1) {
+        edges {
+            node {
+                name
+            }
+        }
+        pageInfo {
+            hasNextPage
+            hasPreviousPage
+        }
+    }
+    before: HomeWorldsConnection(last: 1, before: "a2V5c2V0Om5hbWU9c1ZHRjBiMjlwYm1VPSxpZD1pMQ==") {
+        edges {
+            node {
+                name
+            }
+        }
+        pageInfo {
+            hasNextPage
+            hasPreviousPage
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "before": {
+          "edges": [
+            {
+              "node": {
+                "name": "Alderaan"
+              }
+            }
+          ],
+          "pageInfo": {
+            "hasNextPage": false,
+            "hasPreviousPage": false
+          }
+        },
+        "last": {
+          "edges": [
+            {
+              "node": {
+                "name": "Tatooine"
+              }
+            }
+          ],
+          "pageInfo": {
+            "hasNextPage": false,
+            "hasPreviousPage": true
+          }
+        }
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn keyset_connection_order() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HomeWorldsConnection(first: 1, after: "a2V5c2V0OmlkPWky", order: [{column: id, direction: DESC}]) {
+        edges {
+            cursor
+            node {
+                id
+                name
+            }
+        }
+        pageInfo {
+            hasNextPage
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorldsConnection": {
+          "edges": [
+            {
+              "cursor": "a2V5c2V0OmlkPWkx",
+              "node": {
+                "id": 1,
+                "name": "Tatooine"
+              }
+            }
+          ],
+          "pageInfo": {
+            "hasNextPage": false
+          }
+        }
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn keyset_connection_invalid_cursor() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    // A cursor encoding other order columns is rejected
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HomeWorldsConnection(first: 1, after: "a2V5c2V0OmlkPWky") {
+        edges {
+            node {
+                name
+            }
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      null,
+      [
+        {
+          "locations": [
+            {
+              "column": 5,
+              "line": 3
+            }
+          ],
+          "message": "Invalid cursor passed to `after`",
+          "path": [
+            "HomeWorldsConnection"
+          ]
+        }
+      ]
+    ]
+    "###
+    );
+}
+
+#[test]
+fn keyset_connection_unsupported_order() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HomeWorldsConnection(first: 1, order: [{column: name, nulls: FIRST}]) {
+        edges {
+            node {
+                name
+            }
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      null,
+      [
+        {
+          "locations": [
+            {
+              "column": 5,
+              "line": 3
+            }
+          ],
+          "message": "Invalid order argument at `order[0].nulls`",
+          "path": [
+            "HomeWorldsConnection"
+          ]
+        }
+      ]
+    ]
+    "###
+    );
+}
@@ -27,6 +27,8 @@ impl CustomizeConnection<DbConnection, ::diesel::r2d2::Error> for TestTransactio
         conn: &mut DbConnection,
     ) -> ::std::result::Result<(), ::diesel::r2d2::Error> {
         conn.begin_test_transaction().unwrap();
+        #[cfg(all(feature = "sqlite", feature = "regex"))]
+        wundergraph::diesel_ext::register_regexp_function(conn).unwrap();
         Ok(())
     }
 }
@@ -86,6 +88,8 @@ fn run_migrations(conn: &DbConnection, which: &str) {
         migration_path.push("pg");
     } else if cfg!(feature = "sqlite") {
         migration_path.push("sqlite");
+    } else if cfg!(feature = "mysql") {
+        migration_path.push("mysql");
     }
     let pending_migrations =
         ::diesel_migrations::mark_migrations_in_directory(conn, &migration_path)
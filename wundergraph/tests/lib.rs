@@ -1,7 +1,7 @@
 extern crate diesel;
 extern crate diesel_migrations;
 extern crate juniper;
-extern crate serde_json;
+extern crate serde_json_internal as serde_json;
 extern crate wundergraph;
 extern crate wundergraph_bench;
 extern crate wundergraph_example;
@@ -12,14 +12,18 @@ extern crate insta;
 
 mod helper;
 
+mod aggregate;
 mod alias;
+mod connection;
 mod limit_offset;
 mod mutations;
 mod order;
+mod primary_keys;
 mod query;
 mod query_nested;
 mod simple;
 mod type_checking;
+mod unique;
 
 #[cfg(feature = "postgres")]
 type DbConnection = diesel::pg::PgConnection;
@@ -27,5 +31,8 @@ type DbConnection = diesel::pg::PgConnection;
 #[cfg(feature = "sqlite")]
 type DbConnection = diesel::sqlite::SqliteConnection;
 
-#[cfg(not(any(feature = "postgres", feature = "sqlite")))]
-compile_error!("At least one feature of \"sqlite\" or \"postgres\" needs to be enabled");
+#[cfg(feature = "mysql")]
+type DbConnection = diesel::mysql::MysqlConnection;
+
+#[cfg(not(any(feature = "postgres", feature = "sqlite", feature = "mysql")))]
+compile_error!("At least one feature of \"sqlite\", \"postgres\" or \"mysql\" needs to be enabled");
@@ -105,3 +105,243 @@ fn limit_offset() {
 ]"###
     );
 }
+
+#[test]
+fn negative_limit_offset() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(limit: -1) {
+        heroName
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      null,
+      [
+        {
+          "locations": [
+            {
+              "column": 5,
+              "line": 3
+            }
+          ],
+          "message": "Invalid limit argument, expected a non negative integer",
+          "path": [
+            "Heros"
+          ]
+        }
+      ]
+    ]
+    "###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(offset: -1) {
+        heroName
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      null,
+      [
+        {
+          "locations": [
+            {
+              "column": 5,
+              "line": 3
+            }
+          ],
+          "message": "Invalid offset argument, expected a non negative integer",
+          "path": [
+            "Heros"
+          ]
+        }
+      ]
+    ]
+    "###
+    );
+}
+
+#[test]
+fn count() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(limit: 1, filter: {home_world: {name: {eq: "Tatooine"}}}) {
+        heroName
+    }
+    HerosCount(filter: {home_world: {name: {eq: "Tatooine"}}})
+    all: HerosCount
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Heros": [
+          {
+            "heroName": "Luke Skywalker"
+          }
+        ],
+        "HerosCount": 2,
+        "all": 5
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn default_limit() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap()).with_default_limit(2);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros {
+        heroName
+    }
+    Speciess {
+        name
+    }
+    large: Heros(limit: 3) {
+        heroName
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Heros": [
+          {
+            "heroName": "Luke Skywalker"
+          },
+          {
+            "heroName": "Darth Vader"
+          }
+        ],
+        "Speciess": [
+          {
+            "name": "Human"
+          },
+          {
+            "name": "Robot"
+          }
+        ],
+        "large": [
+          {
+            "heroName": "Luke Skywalker"
+          },
+          {
+            "heroName": "Darth Vader"
+          },
+          {
+            "heroName": "Han Solo"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn max_limit() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap()).with_max_limit(1);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Speciess(limit: 2) {
+        name
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      null,
+      [
+        {
+          "locations": [
+            {
+              "column": 5,
+              "line": 3
+            }
+          ],
+          "message": "Requested `limit` of 2 exceeds the maximum of 1",
+          "path": [
+            "Speciess"
+          ]
+        }
+      ]
+    ]
+    "###
+    );
+
+    // Limits up to the maximum are accepted
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Speciess(limit: 1) {
+        name
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Speciess": [
+          {
+            "name": "Human"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
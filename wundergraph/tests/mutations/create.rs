@@ -241,3 +241,72 @@ mutation NewHeros {
 ]"###
     );
 }
+
+#[test]
+fn create_with_primary_key() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewFriend {
+  CreateFriend(NewFriend: {heroId: 1, friendId: 5}) {
+    friend_id {
+      heroName
+    }
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "CreateFriend": {
+      "friend_id": {
+        "heroName": "Wilhuff Tarkin"
+      }
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewFriends {
+  CreateFriends(NewFriends: [{heroId: 5, friendId: 1}, {heroId: 5, friendId: 3}]) {
+    friend_id {
+      heroName
+    }
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "CreateFriends": [
+      {
+        "friend_id": {
+          "heroName": "Luke Skywalker"
+        }
+      },
+      {
+        "friend_id": {
+          "heroName": "Han Solo"
+        }
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
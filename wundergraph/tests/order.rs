@@ -116,7 +116,7 @@ fn invalid_order() {
               "line": 3
             }
           ],
-          "message": "Could not build filter from arguments",
+          "message": "Invalid order argument at `order`",
           "path": [
             "Heros"
           ]
@@ -126,3 +126,468 @@ fn invalid_order() {
     "###
     );
 }
+
+#[test]
+fn order_by_relevance() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewHero {
+  CreateHero(NewHero: {name: "Han Solo Solo", species: 1}) {
+    heroName
+  }
+}
+"#,
+    );
+    assert!(res.is_ok());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(search: "solo", order: [{column: RELEVANCE, direction: DESC}]) {
+        heroName
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Han Solo Solo"
+      },
+      {
+        "heroName": "Han Solo"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(search: "solo", order: [{column: RELEVANCE}]) {
+        heroName
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Han Solo"
+      },
+      {
+        "heroName": "Han Solo Solo"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn order_by_relevance_requires_search() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(order: [{column: RELEVANCE, direction: DESC}]) {
+        heroName
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  null,
+  [
+    {
+      "locations": [
+        {
+          "column": 5,
+          "line": 3
+        }
+      ],
+      "message": "Invalid order argument at `order[0].column`",
+      "path": [
+        "Heros"
+      ]
+    }
+  ]
+]"###
+    );
+}
+
+#[test]
+fn order_by_has_one_field() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(
+        filter: {home_world: {name: {like: \"%a%\"}}},
+        order: [{column: home_world_name, direction: DESC}, {column: heroName}]
+    ) {
+        heroName
+        home_world {
+            name
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Heros": [
+          {
+            "heroName": "Darth Vader",
+            "home_world": {
+              "name": "Tatooine"
+            }
+          },
+          {
+            "heroName": "Luke Skywalker",
+            "home_world": {
+              "name": "Tatooine"
+            }
+          },
+          {
+            "heroName": "Leia Organa",
+            "home_world": {
+              "name": "Alderaan"
+            }
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn order_nulls_first() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(order: [{column: hair_color, nulls: FIRST}, {column: heroName}]) {
+        heroName
+        hair_color
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Heros": [
+          {
+            "hair_color": null,
+            "heroName": "Darth Vader"
+          },
+          {
+            "hair_color": null,
+            "heroName": "Han Solo"
+          },
+          {
+            "hair_color": null,
+            "heroName": "Leia Organa"
+          },
+          {
+            "hair_color": null,
+            "heroName": "Wilhuff Tarkin"
+          },
+          {
+            "hair_color": "blond",
+            "heroName": "Luke Skywalker"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn order_nulls_last() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(order: [{column: home_world_name, direction: DESC, nulls: LAST}, {column: heroName}]) {
+        heroName
+        home_world {
+            name
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Heros": [
+          {
+            "heroName": "Darth Vader",
+            "home_world": {
+              "name": "Tatooine"
+            }
+          },
+          {
+            "heroName": "Luke Skywalker",
+            "home_world": {
+              "name": "Tatooine"
+            }
+          },
+          {
+            "heroName": "Leia Organa",
+            "home_world": {
+              "name": "Alderaan"
+            }
+          },
+          {
+            "heroName": "Han Solo",
+            "home_world": null
+          },
+          {
+            "heroName": "Wilhuff Tarkin",
+            "home_world": null
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn default_order() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HomeWorlds {
+        name
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorlds": [
+          {
+            "name": "Alderaan"
+          },
+          {
+            "name": "Tatooine"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HomeWorlds(order: [{column: name, direction: DESC}]) {
+        name
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorlds": [
+          {
+            "name": "Tatooine"
+          },
+          {
+            "name": "Alderaan"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn default_order_nested() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(filter: {heroName: {eq: \"Luke Skywalker\"}}) {
+        heroName
+        appears_in {
+            episode
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Heros": [
+          {
+            "appears_in": [
+              {
+                "episode": "JEDI"
+              },
+              {
+                "episode": "EMPIRE"
+              },
+              {
+                "episode": "NEWHOPE"
+              }
+            ],
+            "heroName": "Luke Skywalker"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn order_by_has_many_count() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HomeWorlds(order: [{column: heros_COUNT, direction: DESC}]) {
+        name
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorlds": [
+          {
+            "name": "Tatooine"
+          },
+          {
+            "name": "Alderaan"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(order: [{column: friends_COUNT}, {column: heroName}]) {
+        heroName
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Heros": [
+          {
+            "heroName": "Darth Vader"
+          },
+          {
+            "heroName": "Wilhuff Tarkin"
+          },
+          {
+            "heroName": "Han Solo"
+          },
+          {
+            "heroName": "Leia Organa"
+          },
+          {
+            "heroName": "Luke Skywalker"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
@@ -0,0 +1,87 @@
+use crate::helper::*;
+use wundergraph_example::MyContext;
+
+#[test]
+fn query_by_primary_keys() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HeroByIds(primaryKeys: [{id: 4}, {id: 42}, {id: 1}, {id: 4}]) {
+        id
+        heroName
+        species {
+            name
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HeroByIds": [
+          {
+            "heroName": "Leia Organa",
+            "id": 4,
+            "species": {
+              "name": "Human"
+            }
+          },
+          null,
+          {
+            "heroName": "Luke Skywalker",
+            "id": 1,
+            "species": {
+              "name": "Human"
+            }
+          },
+          {
+            "heroName": "Leia Organa",
+            "id": 4,
+            "species": {
+              "name": "Human"
+            }
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn query_by_empty_primary_keys() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HomeWorldByIds(primaryKeys: []) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorldByIds": []
+      },
+      []
+    ]
+    "###
+    );
+}
@@ -107,7 +107,7 @@ fn query_filter_like() {
 }
 
 #[test]
-fn query_filter_cannot_use_like_with_non_strings() {
+fn query_filter_ilike() {
     let (schema, pool) = get_example_schema();
     let ctx = MyContext::new(pool.get().unwrap());
 
@@ -116,31 +116,43 @@ fn query_filter_cannot_use_like_with_non_strings() {
         &ctx,
         r#"
 {
-    Heros(filter: {heroName: {id: "Leia %"}}) {
+    Heros(filter: {heroName: {ilike: "leia %"}}) {
         heroName
     }
 }
 "#,
     );
 
-    assert!(res.is_err());
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Leia Organa"
+      }
+    ]
+  },
+  []
+]"###
+    );
 }
 
 #[test]
-fn query_filter_eq_any() {
+fn query_filter_eq_ignore_case() {
     let (schema, pool) = get_example_schema();
     let ctx = MyContext::new(pool.get().unwrap());
 
     let res = execute_query(
         &schema,
         &ctx,
-        "
+        r#"
 {
-    Heros(filter: {id: {eq_any: [1, 4]}}) {
+    Heros(filter: {heroName: {eq_ignore_case: "luke SKYWALKER"}}) {
         heroName
     }
 }
-",
+"#,
     );
 
     assert!(res.is_ok());
@@ -150,9 +162,6 @@ fn query_filter_eq_any() {
     "Heros": [
       {
         "heroName": "Luke Skywalker"
-      },
-      {
-        "heroName": "Leia Organa"
       }
     ]
   },
@@ -162,7 +171,7 @@ fn query_filter_eq_any() {
 }
 
 #[test]
-fn query_filter_and() {
+fn query_filter_starts_with() {
     let (schema, pool) = get_example_schema();
     let ctx = MyContext::new(pool.get().unwrap());
 
@@ -171,7 +180,7 @@ fn query_filter_and() {
         &ctx,
         r#"
 {
-    Heros(filter: {and: [{heroName: {like: "Leia %"}}, {id: {eq: 4}}]}) {
+    Heros(filter: {heroName: {starts_with: "Han"}}) {
         heroName
     }
 }
@@ -184,7 +193,7 @@ fn query_filter_and() {
   {
     "Heros": [
       {
-        "heroName": "Leia Organa"
+        "heroName": "Han Solo"
       }
     ]
   },
@@ -194,7 +203,7 @@ fn query_filter_and() {
 }
 
 #[test]
-fn query_filter_or() {
+fn query_filter_ends_with() {
     let (schema, pool) = get_example_schema();
     let ctx = MyContext::new(pool.get().unwrap());
 
@@ -203,7 +212,7 @@ fn query_filter_or() {
         &ctx,
         r#"
 {
-    Heros(filter: {or: [{heroName: {like: "Leia %"}}, {id: {eq: 1}}]}) {
+    Heros(filter: {heroName: {ends_with: "Organa"}}) {
         heroName
     }
 }
@@ -215,9 +224,6 @@ fn query_filter_or() {
         res.as_json(), @r###"[
   {
     "Heros": [
-      {
-        "heroName": "Luke Skywalker"
-      },
       {
         "heroName": "Leia Organa"
       }
@@ -229,7 +235,7 @@ fn query_filter_or() {
 }
 
 #[test]
-fn query_filter_is_null() {
+fn query_filter_contains() {
     let (schema, pool) = get_example_schema();
     let ctx = MyContext::new(pool.get().unwrap());
 
@@ -238,7 +244,7 @@ fn query_filter_is_null() {
         &ctx,
         r#"
 {
-    Heros(filter: {hair_color: {is_null: true}}) {
+    Heros(filter: {heroName: {contains: "th V"}}) {
         heroName
     }
 }
@@ -252,15 +258,103 @@ fn query_filter_is_null() {
     "Heros": [
       {
         "heroName": "Darth Vader"
-      },
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn query_filter_contains_escapes_wildcards() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {heroName: {contains: "%"}}) {
+        heroName
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": []
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+#[cfg(any(not(feature = "sqlite"), feature = "regex"))]
+fn query_filter_matches() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {heroName: {matches: "^[LH].* (Skywalker|Solo)$"}}) {
+        heroName
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
       {
-        "heroName": "Han Solo"
+        "heroName": "Luke Skywalker"
       },
       {
-        "heroName": "Leia Organa"
-      },
+        "heroName": "Han Solo"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+#[cfg(any(not(feature = "sqlite"), feature = "regex"))]
+fn query_filter_imatches() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {heroName: {imatches: "^darth"}}) {
+        heroName
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
       {
-        "heroName": "Wilhuff Tarkin"
+        "heroName": "Darth Vader"
       }
     ]
   },
@@ -270,7 +364,7 @@ fn query_filter_is_null() {
 }
 
 #[test]
-fn query_filter_is_not_null() {
+fn query_filter_search() {
     let (schema, pool) = get_example_schema();
     let ctx = MyContext::new(pool.get().unwrap());
 
@@ -279,7 +373,7 @@ fn query_filter_is_not_null() {
         &ctx,
         r#"
 {
-    Heros(filter: {hair_color: {is_null: false}}) {
+    Heros(search: "skywalker") {
         heroName
     }
 }
@@ -302,7 +396,7 @@ fn query_filter_is_not_null() {
 }
 
 #[test]
-fn query_filter_is_null_cannot_be_used_with_not_nullable_fields() {
+fn query_filter_cannot_use_like_with_non_strings() {
     let (schema, pool) = get_example_schema();
     let ctx = MyContext::new(pool.get().unwrap());
 
@@ -311,7 +405,7 @@ fn query_filter_is_null_cannot_be_used_with_not_nullable_fields() {
         &ctx,
         r#"
 {
-    Heros(filter: {id: {is_null: true}}) {
+    Heros(filter: {heroName: {id: "Leia %"}}) {
         heroName
     }
 }
@@ -322,20 +416,20 @@ fn query_filter_is_null_cannot_be_used_with_not_nullable_fields() {
 }
 
 #[test]
-fn query_filter_type_could_have_2_modifiers() {
+fn query_filter_eq_any() {
     let (schema, pool) = get_example_schema();
     let ctx = MyContext::new(pool.get().unwrap());
 
     let res = execute_query(
         &schema,
         &ctx,
-        r#"
+        "
 {
-    Heros(filter: {and: [{hair_color: {is_null: false}}, {hair_color: {like: "%"}}]}) {
+    Heros(filter: {id: {eq_any: [1, 4]}}) {
         heroName
     }
 }
-"#,
+",
     );
 
     assert!(res.is_ok());
@@ -345,6 +439,9 @@ fn query_filter_type_could_have_2_modifiers() {
     "Heros": [
       {
         "heroName": "Luke Skywalker"
+      },
+      {
+        "heroName": "Leia Organa"
       }
     ]
   },
@@ -354,7 +451,7 @@ fn query_filter_type_could_have_2_modifiers() {
 }
 
 #[test]
-fn query_filter_not() {
+fn query_filter_gt_lt() {
     let (schema, pool) = get_example_schema();
     let ctx = MyContext::new(pool.get().unwrap());
 
@@ -363,12 +460,13 @@ fn query_filter_not() {
         &ctx,
         "
 {
-    Heros(filter: {not: {id: {eq: 1}}}) {
+    Heros(filter: {id: {gt: 1, lt: 4}}) {
         heroName
     }
 }
 ",
     );
+
     assert!(res.is_ok());
     assert_json_snapshot!(
         res.as_json(), @r###"[
@@ -379,12 +477,41 @@ fn query_filter_not() {
       },
       {
         "heroName": "Han Solo"
-      },
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn query_filter_gte_lte() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {heroName: {gte: "Han Solo", lte: "Leia Organa"}}) {
+        heroName
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
       {
-        "heroName": "Leia Organa"
+        "heroName": "Han Solo"
       },
       {
-        "heroName": "Wilhuff Tarkin"
+        "heroName": "Leia Organa"
       }
     ]
   },
@@ -394,43 +521,67 @@ fn query_filter_not() {
 }
 
 #[test]
-fn filter_on_field() {
+fn query_filter_between() {
     let (schema, pool) = get_example_schema();
     let ctx = MyContext::new(pool.get().unwrap());
 
     let res = execute_query(
         &schema,
         &ctx,
-        r#"
+        "
 {
-  Speciess {
-    id
-    name
-    heros(filter: {heroName: {like: "Luke%"}}) {
-      heroName
+    Heros(filter: {id: {between: {from: 2, to: 4}}}) {
+        heroName
     }
-  }
 }
-"#,
+",
     );
+
     assert!(res.is_ok());
     assert_json_snapshot!(
         res.as_json(), @r###"[
   {
-    "Speciess": [
+    "Heros": [
       {
-        "heros": [
-          {
-            "heroName": "Luke Skywalker"
-          }
-        ],
-        "id": 1,
-        "name": "Human"
+        "heroName": "Darth Vader"
       },
       {
-        "heros": [],
-        "id": 2,
-        "name": "Robot"
+        "heroName": "Han Solo"
+      },
+      {
+        "heroName": "Leia Organa"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn query_filter_between_exclusive() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(filter: {id: {between: {from: 2, to: 4, inclusive: false}}}) {
+        heroName
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Han Solo"
       }
     ]
   },
@@ -438,3 +589,779 @@ fn filter_on_field() {
 ]"###
     );
 }
+
+#[cfg(feature = "postgres")]
+#[test]
+fn query_filter_nullable_between() {
+    let (schema, pool) = get_bench_schema();
+    let ctx = pool.get().unwrap();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Employes(filter: {reports_to: {between: {from: 1, to: 2}}}, order: [{column: employee_id}]) {
+        employee_id
+        reports_to
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Employes": [
+          {
+            "employee_id": 2,
+            "reports_to": 1
+          },
+          {
+            "employee_id": 3,
+            "reports_to": 2
+          },
+          {
+            "employee_id": 4,
+            "reports_to": 2
+          },
+          {
+            "employee_id": 5,
+            "reports_to": 2
+          },
+          {
+            "employee_id": 6,
+            "reports_to": 1
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn query_filter_and() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {and: [{heroName: {like: "Leia %"}}, {id: {eq: 4}}]}) {
+        heroName
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Leia Organa"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn query_filter_or() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {or: [{heroName: {like: "Leia %"}}, {id: {eq: 1}}]}) {
+        heroName
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Luke Skywalker"
+      },
+      {
+        "heroName": "Leia Organa"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn query_filter_is_null() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {hair_color: {is_null: true}}) {
+        heroName
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Darth Vader"
+      },
+      {
+        "heroName": "Han Solo"
+      },
+      {
+        "heroName": "Leia Organa"
+      },
+      {
+        "heroName": "Wilhuff Tarkin"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn query_filter_is_not_null() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {hair_color: {is_null: false}}) {
+        heroName
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Luke Skywalker"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn query_filter_is_null_cannot_be_used_with_not_nullable_fields() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {id: {is_null: true}}) {
+        heroName
+    }
+}
+"#,
+    );
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn query_filter_type_could_have_2_modifiers() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {and: [{hair_color: {is_null: false}}, {hair_color: {like: "%"}}]}) {
+        heroName
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Luke Skywalker"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn query_filter_not() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(filter: {not: {id: {eq: 1}}}) {
+        heroName
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Darth Vader"
+      },
+      {
+        "heroName": "Han Solo"
+      },
+      {
+        "heroName": "Leia Organa"
+      },
+      {
+        "heroName": "Wilhuff Tarkin"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn filter_on_field() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+  Speciess {
+    id
+    name
+    heros(filter: {heroName: {like: "Luke%"}}) {
+      heroName
+    }
+  }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Speciess": [
+      {
+        "heros": [
+          {
+            "heroName": "Luke Skywalker"
+          }
+        ],
+        "id": 1,
+        "name": "Human"
+      },
+      {
+        "heros": [],
+        "id": 2,
+        "name": "Robot"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn query_filter_array_operations() {
+    let (schema, pool) = get_bench_schema();
+    let ctx = pool.get().unwrap();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Films(filter: {film_id: {lte: 10}, special_features: {contains: ["Trailers", "Deleted Scenes"]}}, order: [{column: film_id}]) {
+        film_id
+        special_features
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Films": [
+      {
+        "film_id": 2,
+        "special_features": [
+          "Trailers",
+          "Deleted Scenes"
+        ]
+      },
+      {
+        "film_id": 3,
+        "special_features": [
+          "Trailers",
+          "Deleted Scenes"
+        ]
+      },
+      {
+        "film_id": 7,
+        "special_features": [
+          "Trailers",
+          "Deleted Scenes"
+        ]
+      },
+      {
+        "film_id": 9,
+        "special_features": [
+          "Trailers",
+          "Deleted Scenes"
+        ]
+      },
+      {
+        "film_id": 10,
+        "special_features": [
+          "Trailers",
+          "Deleted Scenes"
+        ]
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Films(filter: {film_id: {lte: 10}, special_features: {contained_by: ["Trailers", "Commentaries"]}}, order: [{column: film_id}]) {
+        film_id
+        special_features
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Films": [
+      {
+        "film_id": 8,
+        "special_features": [
+          "Trailers"
+        ]
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Films(filter: {film_id: {lte: 10}, special_features: {overlaps: ["Commentaries", "Behind the Scenes"]}}, order: [{column: film_id}]) {
+        film_id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Films": [
+      {
+        "film_id": 1
+      },
+      {
+        "film_id": 4
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Films(filter: {film_id: {lte: 10}, special_features: {any_eq: "Deleted Scenes", length: {lt: 2}}}, order: [{column: film_id}]) {
+        film_id
+        special_features
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Films": [
+      {
+        "film_id": 5,
+        "special_features": [
+          "Deleted Scenes"
+        ]
+      },
+      {
+        "film_id": 6,
+        "special_features": [
+          "Deleted Scenes"
+        ]
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn query_json_columns() {
+    let (schema, pool) = get_bench_schema();
+    let ctx = pool.get().unwrap();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Films(filter: {film_id: {lte: 3}}, order: [{column: film_id}]) {
+        film_id
+        metadata
+        attributes
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Films": [
+      {
+        "attributes": {
+          "restored": true
+        },
+        "film_id": 1,
+        "metadata": {
+          "awards": [
+            "Best Picture"
+          ],
+          "locations": {
+            "country": "Canada"
+          },
+          "studio": "Pinewood"
+        }
+      },
+      {
+        "attributes": {
+          "restored": false
+        },
+        "film_id": 2,
+        "metadata": {
+          "awards": [],
+          "studio": "Shepperton"
+        }
+      },
+      {
+        "attributes": null,
+        "film_id": 3,
+        "metadata": {
+          "locations": {
+            "country": "China"
+          },
+          "studio": "Pinewood"
+        }
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn query_filter_json_operations() {
+    let (schema, pool) = get_bench_schema();
+    let ctx = pool.get().unwrap();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Films(filter: {metadata: {has_key: "awards"}}, order: [{column: film_id}]) {
+        film_id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Films": [
+      {
+        "film_id": 1
+      },
+      {
+        "film_id": 2
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Films(filter: {metadata: {contains: "{\"studio\": \"Pinewood\"}"}}, order: [{column: film_id}]) {
+        film_id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Films": [
+      {
+        "film_id": 1
+      },
+      {
+        "film_id": 3
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Films(filter: {metadata: {path_eq: {path: ["locations", "country"], value: "\"China\""}}}, order: [{column: film_id}]) {
+        film_id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Films": [
+      {
+        "film_id": 3
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Films(filter: {attributes: {path_eq: {path: ["restored"], value: "true"}}}, order: [{column: film_id}]) {
+        film_id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Films": [
+      {
+        "film_id": 1
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn query_filter_invalid_value() {
+    let (schema, pool) = get_bench_schema();
+    let ctx = pool.get().unwrap();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Actors(filter: {last_update: {gt: 1000000000.0}}) {
+        actor_id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      null,
+      [
+        {
+          "locations": [
+            {
+              "column": 5,
+              "line": 3
+            }
+          ],
+          "message": "Invalid filter argument at `filter.last_update.gt`",
+          "path": [
+            "Actors"
+          ]
+        }
+      ]
+    ]
+    "###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Actors(filter: {and: [{actor_id: {eq: 1}}, {last_update: {lt: 1000000000.0}}]}) {
+        actor_id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      null,
+      [
+        {
+          "locations": [
+            {
+              "column": 5,
+              "line": 3
+            }
+          ],
+          "message": "Invalid filter argument at `filter.and[1].last_update.lt`",
+          "path": [
+            "Actors"
+          ]
+        }
+      ]
+    ]
+    "###
+    );
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn query_filter_invalid_value_without_strict_filter() {
+    let (schema, pool) = get_bench_schema();
+    let ctx = pool.get().unwrap();
+
+    // Categories do not opt in to `strict_filter`, so the value is ignored
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Categorys(filter: {category_id: {eq: 1}, last_update: {gt: 1000000000.0}}) {
+        category_id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Categorys": [
+          {
+            "category_id": 1
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
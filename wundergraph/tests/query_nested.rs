@@ -212,3 +212,529 @@ fn query_filter_double_nested_negative() {
 ]"###
     );
 }
+
+#[test]
+fn query_filter_has_many_some() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HomeWorlds(filter: {heros: {some: {heroName: {like: "Leia%"}}}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorlds": [
+          {
+            "name": "Alderaan"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Speciess(filter: {heros: {some: {}}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Speciess": [
+          {
+            "name": "Human"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn query_filter_has_many_every() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Speciess(filter: {heros: {every: {home_world: {is_null: false}}}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Speciess": [
+          {
+            "name": "Robot"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HomeWorlds(filter: {heros: {every: {heroName: {not_eq: "Leia Organa"}}}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorlds": [
+          {
+            "name": "Tatooine"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn query_filter_has_many_none() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HomeWorlds(filter: {heros: {none: {heroName: {eq: "Luke Skywalker"}}}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorlds": [
+          {
+            "name": "Alderaan"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Speciess(filter: {heros: {none: {}}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Speciess": [
+          {
+            "name": "Robot"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn query_filter_has_many_count() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HomeWorlds(filter: {heros: {count: {gt: 1}}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorlds": [
+          {
+            "name": "Tatooine"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HomeWorlds(filter: {heros: {count: {gte: 1, lt: 2}}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorlds": [
+          {
+            "name": "Alderaan"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Speciess(filter: {heros: {count: {eq: 0}}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Speciess": [
+          {
+            "name": "Robot"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn query_has_many_limit_offset() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HomeWorlds {
+        name
+        heros(offset: 1) {
+            heroName
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorlds": [
+          {
+            "heros": [],
+            "name": "Alderaan"
+          },
+          {
+            "heros": [
+              {
+                "heroName": "Darth Vader"
+              }
+            ],
+            "name": "Tatooine"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {heroName: {eq: "Luke Skywalker"}}) {
+        heroName
+        appears_in(limit: 2, offset: 1) {
+            episode
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Heros": [
+          {
+            "appears_in": [
+              {
+                "episode": "EMPIRE"
+              },
+              {
+                "episode": "NEWHOPE"
+              }
+            ],
+            "heroName": "Luke Skywalker"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn query_has_many_order_filter() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HomeWorlds {
+        name
+        heros(limit: 1, order: [{column: heroName, direction: DESC}]) {
+            heroName
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorlds": [
+          {
+            "heros": [
+              {
+                "heroName": "Leia Organa"
+              }
+            ],
+            "name": "Alderaan"
+          },
+          {
+            "heros": [
+              {
+                "heroName": "Luke Skywalker"
+              }
+            ],
+            "name": "Tatooine"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Speciess {
+        name
+        heros(filter: {heroName: {like: "L%"}}, limit: 1) {
+            heroName
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Speciess": [
+          {
+            "heros": [
+              {
+                "heroName": "Luke Skywalker"
+              }
+            ],
+            "name": "Human"
+          },
+          {
+            "heros": [],
+            "name": "Robot"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn query_has_many_count_field() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Speciess {
+        name
+        herosCount
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "Speciess": [
+          {
+            "herosCount": 5,
+            "name": "Human"
+          },
+          {
+            "herosCount": 0,
+            "name": "Robot"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HomeWorlds {
+        name
+        heros {
+            heroName
+        }
+        count: herosCount
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorlds": [
+          {
+            "count": 1,
+            "heros": [
+              {
+                "heroName": "Leia Organa"
+              }
+            ],
+            "name": "Alderaan"
+          },
+          {
+            "count": 2,
+            "heros": [
+              {
+                "heroName": "Luke Skywalker"
+              },
+              {
+                "heroName": "Darth Vader"
+              }
+            ],
+            "name": "Tatooine"
+          }
+        ]
+      },
+      []
+    ]
+    "###
+    );
+}
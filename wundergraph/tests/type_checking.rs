@@ -67,6 +67,18 @@ fn test_type_decoration() {
           "description": "List of friends of the current hero",
           "isDeprecated": false,
           "name": "friends"
+        },
+        {
+          "deprecationReason": null,
+          "description": "Number of entities referenced by this field",
+          "isDeprecated": false,
+          "name": "appears_inCount"
+        },
+        {
+          "deprecationReason": null,
+          "description": "Number of entities referenced by this field",
+          "isDeprecated": false,
+          "name": "friendsCount"
         }
       ],
       "name": "Hero"
@@ -0,0 +1,72 @@
+use crate::helper::*;
+use wundergraph_example::MyContext;
+
+#[test]
+fn query_by_unique_field() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HomeWorldByName(name: "Alderaan") {
+        id
+        name
+        heros {
+            heroName
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorldByName": {
+          "heros": [
+            {
+              "heroName": "Leia Organa"
+            }
+          ],
+          "id": 2,
+          "name": "Alderaan"
+        }
+      },
+      []
+    ]
+    "###
+    );
+}
+
+#[test]
+fn query_by_unique_field_not_found() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HomeWorldByName(name: "Naboo") {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"
+    [
+      {
+        "HomeWorldByName": null
+      },
+      []
+    ]
+    "###
+    );
+}
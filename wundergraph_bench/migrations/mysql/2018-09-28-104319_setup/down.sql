@@ -0,0 +1,17 @@
+SET FOREIGN_KEY_CHECKS = 0;
+DROP TABLE film_actor;
+DROP TABLE films;
+DROP TABLE actors;
+DROP TABLE categories;
+DROP TABLE playlist_track;
+DROP TABLE playlists;
+DROP TABLE invoice_lines;
+DROP TABLE invoices;
+DROP TABLE customers;
+DROP TABLE employees;
+DROP TABLE tracks;
+DROP TABLE genres;
+DROP TABLE albums;
+DROP TABLE artists;
+DROP TABLE media_types;
+SET FOREIGN_KEY_CHECKS = 1;
@@ -0,0 +1,2 @@
+ALTER TABLE films DROP COLUMN attributes;
+ALTER TABLE films DROP COLUMN metadata;
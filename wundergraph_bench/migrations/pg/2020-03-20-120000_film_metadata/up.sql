@@ -0,0 +1,11 @@
+ALTER TABLE films ADD COLUMN metadata jsonb;
+ALTER TABLE films ADD COLUMN attributes json;
+
+UPDATE films SET metadata = '{"studio": "Pinewood", "awards": ["Best Picture"], "locations": {"country": "Canada"}}',
+    attributes = '{"restored": true}'
+    WHERE film_id = 1;
+UPDATE films SET metadata = '{"studio": "Shepperton", "awards": []}',
+    attributes = '{"restored": false}'
+    WHERE film_id = 2;
+UPDATE films SET metadata = '{"studio": "Pinewood", "locations": {"country": "China"}}'
+    WHERE film_id = 3;
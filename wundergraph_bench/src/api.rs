@@ -97,10 +97,12 @@ table! {
         last_update -> Timestamp,
         special_features -> Nullable<Array<Text>>,
     //    fulltext -> Tsvector,
+        metadata -> Nullable<Jsonb>,
+        attributes -> Nullable<Json>,
     }
 }
 
-#[cfg(feature = "sqlite")]
+#[cfg(any(feature = "sqlite", feature = "mysql"))]
 table! {
     films (film_id) {
         film_id -> Int4,
@@ -206,6 +208,7 @@ allow_tables_to_appear_in_same_query!(
 #[derive(Clone, Debug, Identifiable, WundergraphEntity)]
 #[table_name = "actors"]
 #[primary_key(actor_id)]
+#[wundergraph(strict_filter)]
 pub struct Actor {
     actor_id: i32,
     first_name: String,
@@ -310,6 +313,10 @@ pub struct Film {
     #[cfg(feature = "postgres")]
     special_features: Option<Vec<String>>,
     //fulltext: Tsvector,
+    #[cfg(feature = "postgres")]
+    metadata: Option<serde_json::Value>,
+    #[cfg(feature = "postgres")]
+    attributes: Option<wundergraph::query_builder::types::Json>,
 }
 
 #[derive(Clone, Debug, Identifiable, WundergraphEntity)]
@@ -551,8 +558,11 @@ pub struct EmployeeChangeset {
 }
 
 #[derive(Insertable, GraphQLInputObject, Clone, Debug, Copy)]
+#[graphql(scalar = "WundergraphScalarValue")]
 #[table_name = "film_actor"]
 pub struct NewFilmActor {
+    actor_id: i16,
+    film_id: i16,
     last_update: NaiveDateTime,
 }
 
@@ -724,6 +734,34 @@ pub struct TrackChangeset {
     //    unit_price: BigDecimal,
 }
 
+// Mysql loads inserted entities by their primary key. All insertables except
+// `NewFilmActor` leave the primary key to `AUTO_INCREMENT`
+#[cfg(feature = "mysql")]
+mod inserted_keys {
+    use super::*;
+    use wundergraph::query_builder::mutations::InsertedKey;
+
+    impl InsertedKey<i32> for NewActor {}
+    impl InsertedKey<i32> for NewAlbum {}
+    impl InsertedKey<i32> for NewArtist {}
+    impl InsertedKey<i32> for NewCategorie {}
+    impl InsertedKey<i32> for NewCustomer {}
+
+    impl InsertedKey<(i16, i16)> for NewFilmActor {
+        fn inserted_key(&self) -> Option<(i16, i16)> {
+            Some((self.actor_id, self.film_id))
+        }
+    }
+
+    impl InsertedKey<i32> for NewFilm {}
+    impl InsertedKey<i32> for NewGenre {}
+    impl InsertedKey<i32> for NewInvoiceLine {}
+    impl InsertedKey<i32> for NewInvoice {}
+    impl InsertedKey<i32> for NewMediaType {}
+    impl InsertedKey<i32> for NewPlaylist {}
+    impl InsertedKey<i32> for NewTrack {}
+}
+
 wundergraph::mutation_object! {
     Mutation{
         Actor(insert = NewActor, update = ActorChangeset, ),
@@ -88,6 +88,9 @@ mod tests {
     #[cfg(feature = "sqlite")]
     const BACKEND: &str = "sqlite";
 
+    #[cfg(feature = "mysql")]
+    const BACKEND: &str = "mysql";
+
     #[cfg(feature = "postgres")]
     const MIGRATION: &[&str] = &[
         "CREATE SCHEMA infer_test;",
@@ -125,6 +128,24 @@ mod tests {
         );"#,
     ];
 
+    #[cfg(feature = "mysql")]
+    const MIGRATION: &[&str] = &[
+        "CREATE TABLE users(id INTEGER NOT NULL AUTO_INCREMENT PRIMARY KEY, name TEXT NOT NULL);",
+        r#"CREATE TABLE posts(
+            id INTEGER NOT NULL AUTO_INCREMENT PRIMARY KEY,
+            author INTEGER REFERENCES users(id),
+            title TEXT NOT NULL,
+            datetime TIMESTAMP NULL,
+            content TEXT
+        );"#,
+        r#"CREATE TABLE comments(
+            id INTEGER NOT NULL AUTO_INCREMENT PRIMARY KEY,
+            post INTEGER REFERENCES posts(id),
+            commenter INTEGER REFERENCES users(id),
+            content TEXT NOT NULL
+        );"#,
+    ];
+
     fn setup_simple_schema(conn: &InferConnection) {
         use diesel::prelude::*;
         use diesel::sql_query;
@@ -141,6 +162,12 @@ mod tests {
                     sql_query(*m).execute(conn).unwrap();
                 }
             }
+            #[cfg(feature = "mysql")]
+            InferConnection::Mysql(conn) => {
+                for m in MIGRATION {
+                    sql_query(*m).execute(conn).unwrap();
+                }
+            }
         }
     }
 
@@ -153,7 +180,7 @@ mod tests {
 
         #[cfg(feature = "postgres")]
         print(&conn, Some("infer_test"), &mut out).unwrap();
-        #[cfg(feature = "sqlite")]
+        #[cfg(any(feature = "sqlite", feature = "mysql"))]
         print(&conn, None, &mut out).unwrap();
 
         let s = String::from_utf8(out).unwrap();
@@ -187,7 +214,7 @@ mod tests {
         let mut api_file = File::create(api).unwrap();
         #[cfg(feature = "postgres")]
         print(&conn, Some("infer_test"), &mut api_file).unwrap();
-        #[cfg(feature = "sqlite")]
+        #[cfg(any(feature = "sqlite", feature = "mysql"))]
         print(&conn, None, &mut api_file).unwrap();
 
         let main = tmp_dir
@@ -224,6 +251,17 @@ mod tests {
         )
         .unwrap();
 
+        #[cfg(feature = "mysql")]
+        write!(
+            main_file,
+            include_str!("template_main.rs"),
+            conn = "MysqlConnection",
+            db_url = std::env::var("DATABASE_URL").unwrap(),
+            migrations = migrations,
+            listen_url = listen_url
+        )
+        .unwrap();
+
         let cargo_toml = tmp_dir.path().join("wundergraph_roundtrip_test/Cargo.toml");
         let mut cargo_toml_file = std::fs::OpenOptions::new()
             .write(true)
@@ -269,6 +307,21 @@ mod tests {
             )
             .unwrap();
         }
+        #[cfg(feature = "mysql")]
+        {
+            writeln!(
+                cargo_toml_file,
+                r#"diesel = {{version = "1.4", features = ["mysql", "chrono"]}}"#
+            )
+            .unwrap();
+
+            writeln!(
+                cargo_toml_file,
+                "wundergraph = {{path = \"{}\", features = [\"mysql\", \"chrono\"] }}",
+                wundergraph_dir
+            )
+            .unwrap();
+        }
         writeln!(cargo_toml_file, r#"juniper = "0.14""#).unwrap();
         writeln!(cargo_toml_file, r#"failure = "0.1""#).unwrap();
         writeln!(cargo_toml_file, r#"actix-web = "1""#).unwrap();
@@ -373,14 +373,18 @@ impl<'a> Display for GraphqlType<'a> {
                     }
                 )?;
             }
-            ColumnType { ref rust_name, .. } if rust_name == "Int2" || rust_name == "SmallInt" => {
+            ColumnType { ref rust_name, .. }
+                if rust_name == "Int2" || rust_name == "SmallInt" || rust_name == "Smallint" =>
+            {
                 write!(f, "i16")?;
             }
             ColumnType { ref rust_name, .. } if rust_name == "Int4" || rust_name == "Integer" => {
                 write!(f, "i32")?;
             }
 
-            ColumnType { ref rust_name, .. } if rust_name == "Int8" || rust_name == "BigInt" => {
+            ColumnType { ref rust_name, .. }
+                if rust_name == "Int8" || rust_name == "BigInt" || rust_name == "Bigint" =>
+            {
                 write!(f, "i64")?;
             }
             ColumnType { ref rust_name, .. } if rust_name == "Float" || rust_name == "Float4" => {
@@ -398,7 +402,9 @@ impl<'a> Display for GraphqlType<'a> {
             ColumnType { ref rust_name, .. } if rust_name == "Timestamptz" => {
                 write!(f, "chrono::DateTime<chrono::offset::Utc>")?;
             }
-            ColumnType { ref rust_name, .. } if rust_name == "Timestamp" => {
+            ColumnType { ref rust_name, .. }
+                if rust_name == "Timestamp" || rust_name == "Datetime" =>
+            {
                 write!(f, "chrono::naive::NaiveDateTime")?;
             }
             ColumnType { ref rust_name, .. } if rust_name == "Uuid" => {
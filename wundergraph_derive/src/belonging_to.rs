@@ -70,9 +70,22 @@ pub fn derive_belonging_to(
             } else {
                 None
             };
+            let mysql = if cfg!(feature = "mysql") {
+                Some(derive_belongs_to(
+                    model,
+                    item,
+                    parent_ty,
+                    &key_ty,
+                    f.sql_name(),
+                    &quote!(diesel::mysql::Mysql),
+                )?)
+            } else {
+                None
+            };
             Ok(quote! {
                 #pg
                 #sqlite
+                #mysql
             })
         })
         .collect::<Result<Vec<_>, _>>()
@@ -105,6 +118,11 @@ fn derive_belongs_to(
         where_clause
             .predicates
             .push(parse_quote!(<__Ctx as wundergraph::WundergraphContext>::Connection: wundergraph::diesel::Connection<Backend = #backend>));
+        // No `__Ctx: QueryModifier<Self, _>` bound here: the parent entity
+        // requires this impl for its filter and field list, which are generic
+        // over the context as well, so the bound would have to be repeated for
+        // each entity reachable via `HasOne` or `HasMany` fields. Like `HasOne`
+        // fields, derived associations therefore skip the `QueryModifier`
     }
     let (impl_generics, _, where_clause) = generics.split_for_impl();
     Ok(quote! {
@@ -132,15 +150,21 @@ fn derive_belongs_to(
                     use wundergraph::WundergraphContext;
                     use wundergraph::query_builder::selection::{LoadingHandler, BoxedQuery};
                     let conn = executor.context().get_connection();
-                    let query = <_ as QueryDsl>::filter(
-                        <BoxedQuery<Self, #backend, __Ctx> as QueryDsl>::select(
-                           <Self as LoadingHandler<#backend, __Ctx>>::build_query(global_args, look_ahead)?,
-                            (
-                                #key_column::default().nullable(),
-                                <Self as LoadingHandler<#backend, __Ctx>>::get_select(look_ahead)?,
-                            )
-                       ),
-                        #key_column::default().nullable().eq_any(keys),
+                    let query = <BoxedQuery<Self, #backend, __Ctx> as QueryDsl>::select(
+                        <Self as wundergraph::query_builder::selection::fields::WundergraphBelongsTo<
+                            <#other as wundergraph::diesel::associations::HasTable>::Table,
+                            #backend,
+                            __Ctx,
+                            #key_column
+                        >>::build_association_query_with(
+                            look_ahead,
+                            #key_column::default().nullable().eq_any(keys.to_vec()),
+                            std::result::Result::Ok,
+                        )?,
+                        (
+                            #key_column::default().nullable(),
+                            <Self as LoadingHandler<#backend, __Ctx>>::get_select(look_ahead)?,
+                        )
                     );
                     #debug
                     <Self as wundergraph::query_builder::selection::fields::WundergraphBelongsTo<
@@ -150,6 +174,26 @@ fn derive_belongs_to(
                     #key_column
                     >>::build_response(query.load(conn)?, global_args, look_ahead, selection, executor)
             }
+
+            fn resolve_count(
+                look_ahead: &wundergraph::juniper::LookAheadSelection<wundergraph::scalar::WundergraphScalarValue>,
+                keys: &[std::option::Option<#key_ty>],
+                executor: &wundergraph::juniper::Executor<__Ctx, wundergraph::scalar::WundergraphScalarValue>,
+            ) -> wundergraph::error::Result<std::collections::HashMap<std::option::Option<#key_ty>, i64>>
+            {
+                use wundergraph::diesel::{ExpressionMethods, NullableExpressionMethods};
+                <Self as wundergraph::query_builder::selection::fields::WundergraphBelongsTo<
+                    <#other as wundergraph::diesel::associations::HasTable>::Table,
+                    #backend,
+                    __Ctx,
+                    #key_column
+                >>::count_associations_with(
+                    look_ahead,
+                    #key_column::default().nullable().eq_any(keys.to_vec()),
+                    executor,
+                    std::result::Result::Ok,
+                )
+            }
         }
     })
 }
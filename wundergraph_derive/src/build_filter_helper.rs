@@ -28,12 +28,23 @@ pub fn derive(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
         None
     };
 
+    let mysql = if cfg!(feature = "mysql") {
+        Some(derive_non_table_filter(
+            &model,
+            item,
+            &quote!(diesel::mysql::Mysql),
+        )?)
+    } else {
+        None
+    };
+
     Ok(wrap_in_dummy_mod(
         "build_filter_helper",
         &model.name,
         &quote! {
             #pg
             #sqlite
+            #mysql
         },
     ))
 }
@@ -103,10 +114,10 @@ pub fn derive_non_table_filter(
             }
 
             fn to_inner_input_value(
-                _f: &#filter,
-                _v: &mut wundergraph::indexmap::IndexMap<&str, wundergraph::juniper::InputValue<wundergraph::scalar::WundergraphScalarValue>>
+                f: &#filter,
+                v: &mut wundergraph::indexmap::IndexMap<&str, wundergraph::juniper::InputValue<wundergraph::scalar::WundergraphScalarValue>>
             ) {
-
+                wundergraph::query_builder::selection::filter::FilterBuildHelper::<#filter, #struct_type #ty_generics, #backend, __Ctx>::inner_to_input_value(f, v)
             }
 
             fn register_fields<'__r>(
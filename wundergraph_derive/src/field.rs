@@ -81,6 +81,10 @@ impl Field {
     pub fn sql_name(&self) -> &syn::Path {
         &self.sql_name
     }
+
+    pub fn is_unique(&self) -> bool {
+        self.flags.nested_item("unique").is_ok()
+    }
 }
 
 #[derive(Debug)]
@@ -61,6 +61,26 @@ use proc_macro::TokenStream;
 ///        to `id` if not given (In this case a primary key field named `id` must exist)
 ///     * `/// Documentation`/`#[doc = "Documentation"]`: Set as GraphQL
 ///       description text.
+///     * `#[wundergraph(full_text_search = "fts_table")]`: Enables full text
+///       search over all `String` fields by adding a `search` argument and
+///       a `RELEVANCE` order value. Names the sqlite FTS5 virtual table
+///       used for the search.
+///     * `#[wundergraph(default_order = "field asc, other_field desc")]`: Order
+///       applied if a query does not provide an `order` argument, both for
+///       top level queries and nested `HasMany` collections. Fields are
+///       named by their GraphQL name, the direction defaults to `asc`.
+///     * `#[wundergraph(default_limit = 50)]`: Limit applied to top level
+///       queries without a `limit` argument. Overrides
+///       `WundergraphContext::default_limit`.
+///     * `#[wundergraph(max_limit = 500)]`: Largest limit a top level query
+///       may request, larger limits are rejected with
+///       `WundergraphError::LimitExceeded`. Overrides
+///       `WundergraphContext::max_limit`.
+///     * `#[wundergraph(strict_filter)]`: Reject `filter` arguments
+///       containing values that could not be interpreted with
+///       `WundergraphError::InvalidFilterArgument`, instead of ignoring
+///       those values. Requires that all filters of the entity write back
+///       each of their fields in `InnerFilter::to_inner_input_value`.
 ///
 /// # Field attributes
 /// All attributes are optional. If no attributes are given the field name needs to
@@ -71,6 +91,12 @@ use proc_macro::TokenStream;
 ///    name as column name for calling into diesels `table!`
 /// * `#[wundergraph(graphql_name = "Foo")]`: Set the GraphQL name of the field
 ///   to the given name. If not set the field name is used as name.
+/// * `#[wundergraph(unique)]`: Marks a field containing a unique value for
+///   each entity. `query_object!` generates an additional
+///   `{Entity}By{Field}(field: ...)` field loading a single entity by this
+///   value. Only supported for fields backed by a column of the entity
+///   table, combine it with other options as
+///   `#[wundergraph(graphql_name = "Foo", unique)]`.
 /// * `#[deprecated(note = "Some Text")]`: Set as GraphQL deprecation notice
 /// * `/// Documentation`/`#[doc = "Documentation"]`: Set as GraphQL
 ///   description text.
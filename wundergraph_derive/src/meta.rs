@@ -170,6 +170,20 @@ impl MetaItem {
         }
     }
 
+    pub fn i64_value(&self) -> Result<i64, Diagnostic> {
+        use syn::Lit::*;
+
+        match *self.lit_value()? {
+            Int(ref i) => i
+                .base10_parse()
+                .map_err(|_| self.value_span().error("Expected an integer".to_owned())),
+            _ => Err(self.span().error(format!(
+                "`{0}` must be in the form `{0} = 42`",
+                self.name().get_ident().expect("Failed to get ident")
+            ))),
+        }
+    }
+
     fn lit_value(&self) -> Result<&syn::Lit, Diagnostic> {
         use syn::Meta::*;
 
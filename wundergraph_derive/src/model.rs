@@ -60,6 +60,69 @@ impl Model {
         &self.primary_keys
     }
 
+    pub fn full_text_search_table(&self) -> Result<Option<String>, Diagnostic> {
+        match self.flags.nested_item("full_text_search") {
+            Ok(item) => item.str_value().map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Parses `#[wundergraph(default_order = "field [asc|desc], ...")]`
+    ///
+    /// Returns the listed field names together with a flag that is
+    /// set for descending order
+    pub fn default_order(&self) -> Result<Vec<(String, bool)>, Diagnostic> {
+        let item = match self.flags.nested_item("default_order") {
+            Ok(item) => item,
+            Err(_) => return Ok(Vec::new()),
+        };
+        let value = item.str_value()?;
+        value
+            .split(',')
+            .map(|part| {
+                let mut words = part.split_whitespace();
+                match (words.next(), words.next(), words.next()) {
+                    (Some(field), None, None) => Ok((field.to_owned(), false)),
+                    (Some(field), Some(dir), None) if dir.eq_ignore_ascii_case("asc") => {
+                        Ok((field.to_owned(), false))
+                    }
+                    (Some(field), Some(dir), None) if dir.eq_ignore_ascii_case("desc") => {
+                        Ok((field.to_owned(), true))
+                    }
+                    _ => Err(item.value_span().error(
+                        "`default_order` must be in the form \
+                         `default_order = \"field [asc|desc], ...\"`",
+                    )),
+                }
+            })
+            .collect()
+    }
+
+    /// Parses `#[wundergraph(default_limit = 50, max_limit = 500)]`
+    ///
+    /// Returns the default and the maximal limit, if set
+    pub fn limits(&self) -> Result<(Option<i64>, Option<i64>), Diagnostic> {
+        let limit = |name| {
+            self.flags
+                .nested_item(name)
+                .ok()
+                .map(|item| item.i64_value().map(|v| (v, item.value_span())))
+                .transpose()
+        };
+        let default_limit = limit("default_limit")?;
+        let max_limit = limit("max_limit")?;
+        if let (Some((default_limit, span)), Some((max_limit, _))) = (default_limit, max_limit) {
+            if default_limit > max_limit {
+                return Err(span.error("`default_limit` must not be greater than `max_limit`"));
+            }
+        }
+        Ok((default_limit.map(|l| l.0), max_limit.map(|l| l.0)))
+    }
+
+    pub fn strict_filter(&self) -> bool {
+        self.flags.nested_item("strict_filter").is_ok()
+    }
+
     pub fn filter_type(&self) -> Option<syn::Path> {
         self.flags.get_flag("filter").ok()
     }
@@ -38,6 +38,14 @@ pub fn is_has_many(ty: &Type) -> bool {
     inner_ty_arg(inner_of_option_ty(ty), "HasMany", 0).is_some()
 }
 
+pub fn has_one_target(ty: &Type) -> Option<&Type> {
+    inner_ty_arg(inner_of_option_ty(ty), "HasOne", 1)
+}
+
+pub fn has_many_foreign_key(ty: &Type) -> Option<&Type> {
+    inner_ty_arg(inner_of_option_ty(ty), "HasMany", 1)
+}
+
 pub fn inner_ty_args<'a>(
     ty: &'a Type,
     type_name: &str,
@@ -2,7 +2,9 @@ use crate::build_filter_helper::derive_non_table_filter;
 use crate::diagnostic_shim::{Diagnostic, DiagnosticShim};
 use crate::field::Field;
 use crate::model::Model;
-use crate::utils::{is_has_many, wrap_in_dummy_mod};
+use crate::utils::{
+    has_many_foreign_key, has_one_target, inner_of_option_ty, is_has_many, wrap_in_dummy_mod,
+};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::parse_quote;
@@ -29,6 +31,16 @@ pub fn derive(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
         None
     };
 
+    let mysql_loading_handler = if cfg!(feature = "mysql") {
+        Some(derive_loading_handler(
+            &model,
+            item,
+            &quote!(diesel::mysql::Mysql),
+        )?)
+    } else {
+        None
+    };
+
     let pg_non_table_field_filter = if cfg!(feature = "postgres") {
         Some(derive_non_table_filter(
             &model,
@@ -49,6 +61,22 @@ pub fn derive(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
         None
     };
 
+    let mysql_non_table_field_filter = if cfg!(feature = "mysql") {
+        Some(derive_non_table_filter(
+            &model,
+            item,
+            &quote!(diesel::mysql::Mysql),
+        )?)
+    } else {
+        None
+    };
+
+    let full_text_search = if cfg!(feature = "sqlite") {
+        derive_full_text_search(&model)?
+    } else {
+        None
+    };
+
     let belongs_to = crate::belonging_to::derive_belonging_to(&model, item)?;
 
     Ok(wrap_in_dummy_mod(
@@ -61,14 +89,103 @@ pub fn derive(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
 
             #pg_loading_handler
             #sqlite_loading_handler
+            #mysql_loading_handler
             #pg_non_table_field_filter
             #sqlite_non_table_field_filter
+            #mysql_non_table_field_filter
+            #full_text_search
 
             #(#belongs_to)*
         },
     ))
 }
 
+fn derive_full_text_search(model: &Model) -> Result<Option<TokenStream>, Diagnostic> {
+    let fts_table = match model.full_text_search_table()? {
+        Some(fts_table) => fts_table,
+        None => return Ok(None),
+    };
+    let table = model.table_type()?;
+
+    Ok(Some(quote! {
+        impl wundergraph::query_builder::selection::search::FullTextSearch for #table::table {
+            const FTS_TABLE: &'static str = #fts_table;
+        }
+    }))
+}
+
+fn is_string_ty(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(ref ty) = *inner_of_option_ty(ty) {
+        ty.path
+            .segments
+            .iter()
+            .last()
+            .map_or(false, |s| s.ident == "String")
+    } else {
+        false
+    }
+}
+
+fn derive_search_handler(
+    model: &Model,
+    backend: &TokenStream,
+) -> Result<Option<TokenStream>, Diagnostic> {
+    if model.full_text_search_table()?.is_none() {
+        return Ok(None);
+    }
+    let table = model.table_type()?;
+    let columns = model
+        .fields()
+        .iter()
+        .filter(|f| !is_has_many(&f.ty) && is_string_ty(&f.ty))
+        .map(|f| {
+            let column = f.sql_name();
+            quote!(#table::#column)
+        })
+        .collect::<Vec<_>>();
+    if columns.is_empty() {
+        return Err(Span::call_site()
+            .error("`full_text_search` requires at least one field of the type `String`"));
+    }
+
+    Ok(Some(quote! {
+        const FULL_TEXT_SEARCH: bool = true;
+
+        fn build_full_text_search_filter(
+            query: &str,
+        ) -> std::option::Option<std::boxed::Box<dyn wundergraph::diesel_ext::BoxableFilter<
+            #table::table,
+            #backend,
+            SqlType = wundergraph::diesel::sql_types::Bool,
+        >>> {
+            std::option::Option::Some(std::boxed::Box::new(
+                wundergraph::query_builder::selection::search::FullTextMatch::<#table::table, _>::new(
+                    (#(#columns,)*),
+                    query.to_owned(),
+                ),
+            ))
+        }
+
+        fn build_relevance_order(
+            query: &str,
+            order: wundergraph::query_builder::selection::order::Order,
+            nulls: std::option::Option<wundergraph::query_builder::selection::order::Nulls>,
+        ) -> std::option::Option<wundergraph::query_builder::selection::order::OrderClauses<
+            #table::table,
+            #backend,
+        >> {
+            std::option::Option::Some(wundergraph::query_builder::selection::order::order_by_expression(
+                wundergraph::query_builder::selection::search::Relevance::<#table::table, _>::new(
+                    (#(#columns,)*),
+                    query.to_owned(),
+                ),
+                order,
+                nulls,
+            ))
+        }
+    }))
+}
+
 fn derive_loading_handler(
     model: &Model,
     item: &syn::DeriveInput,
@@ -145,6 +262,39 @@ fn derive_loading_handler(
         |p| quote!(#p),
     );
 
+    let reference_order = derive_reference_order(model, backend)?;
+    let aggregates = derive_aggregates(model, backend)?;
+    let default_order = derive_default_order(model)?;
+    let unique_fields = derive_unique_fields(model)?;
+    let filter_by_primary_keys = derive_filter_by_primary_keys(model, backend)?;
+    let search = derive_search_handler(model, backend)?;
+    let search_argument = if search.is_some() {
+        Some(quote! {
+            let arg = registry.arg_with_default::<std::option::Option<std::string::String>>(
+                "search",
+                &std::option::Option::None,
+                &(),
+            );
+            let field = field.argument(arg);
+        })
+    } else {
+        None
+    };
+    let (default_limit, max_limit) = model.limits()?;
+    let default_limit = default_limit.map(
+        |l| quote!(const DEFAULT_LIMIT: std::option::Option<i64> = std::option::Option::Some(#l);),
+    );
+    let max_limit = max_limit.map(
+        |l| quote!(const MAX_LIMIT: std::option::Option<i64> = std::option::Option::Some(#l);),
+    );
+    let strict_filter = if model.strict_filter() {
+        Some(quote!(
+            const STRICT_FILTER: bool = true;
+        ))
+    } else {
+        None
+    };
+
     let mut generics = item.generics.clone();
     generics
         .params
@@ -178,6 +328,29 @@ fn derive_loading_handler(
                         &std::option::Option::None,
                         &std::default::Default::default(),
                     );
+                let field = field.argument(arg);
+                #search_argument
+                let arg = registry.arg_with_default::<std::option::Option<i32>>(
+                    "limit",
+                    &std::option::Option::None,
+                    &(),
+                );
+                let field = field.argument(arg);
+                let arg = registry.arg_with_default::<std::option::Option<i32>>(
+                    "offset",
+                    &std::option::Option::None,
+                    &(),
+                );
+                let field = field.argument(arg);
+                let arg = registry.arg_with_default::<
+                    std::option::Option<std::vec::Vec<
+                        wundergraph::query_builder::selection::order::OrderBy<Self, #backend, __Ctx>
+                    >>
+                    >(
+                        "order",
+                        &std::option::Option::None,
+                        &std::default::Default::default(),
+                    );
                 field.argument(arg)
             }
         }
@@ -194,6 +367,11 @@ fn derive_loading_handler(
             const FIELD_NAMES: &'static [&'static str] = &[#(stringify!(#field_names),)*];
             const TYPE_NAME: &'static str = stringify!(#struct_type);
             const TYPE_DESCRIPTION: std::option::Option<&'static str> = #type_description;
+            #default_order
+            #default_limit
+            #max_limit
+            #strict_filter
+            #unique_fields
 
             fn field_description(idx: usize) -> std::option::Option<&'static str> {
                 match idx {
@@ -208,6 +386,384 @@ fn derive_loading_handler(
                     _ => std::option::Option::None,
                 }
             }
+
+            #reference_order
+            #aggregates
+            #filter_by_primary_keys
+            #search
         }
     })
 }
+
+fn derive_default_order(model: &Model) -> Result<Option<TokenStream>, Diagnostic> {
+    let default_order = model.default_order()?;
+    if default_order.is_empty() {
+        return Ok(None);
+    }
+    let fields = model.fields();
+    let is_order_column = |name: &str| {
+        fields.iter().any(|f| {
+            let graphql_name = f.graphql_name();
+            let graphql_name = quote!(#graphql_name).to_string();
+            if is_has_many(&f.ty) {
+                name == format!("{}_COUNT", graphql_name)
+            } else if has_one_target(&f.ty).is_some() {
+                name.len() > graphql_name.len() + 1
+                    && name.starts_with(&graphql_name)
+                    && name[graphql_name.len()..].starts_with('_')
+            } else {
+                name == graphql_name
+            }
+        })
+    };
+    let order = default_order
+        .iter()
+        .map(|(name, desc)| {
+            if !is_order_column(name) {
+                return Err(
+                    Span::call_site().error(format!("Unknown field `{}` in `default_order`", name))
+                );
+            }
+            let order = if *desc {
+                quote!(wundergraph::query_builder::selection::order::Order::Desc)
+            } else {
+                quote!(wundergraph::query_builder::selection::order::Order::Asc)
+            };
+            Ok(quote!((#name, #order)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Some(quote! {
+        const DEFAULT_ORDER: &'static [(
+            &'static str,
+            wundergraph::query_builder::selection::order::Order,
+        )] = &[#(#order,)*];
+    }))
+}
+
+fn derive_unique_fields(model: &Model) -> Result<Option<TokenStream>, Diagnostic> {
+    let unique_fields = model
+        .fields()
+        .iter()
+        .filter(|f| f.is_unique())
+        .map(|f| {
+            if is_has_many(&f.ty) || has_one_target(&f.ty).is_some() {
+                return Err(f.span.error(
+                    "`unique` is only supported for fields backed by a column of the entity table",
+                ));
+            }
+            let graphql_name = f.graphql_name();
+            Ok(quote!(stringify!(#graphql_name)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if unique_fields.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(quote! {
+        const UNIQUE_FIELDS: &'static [&'static str] = &[#(#unique_fields,)*];
+    }))
+}
+
+// Entities with a single primary key column are loaded by an `IN`
+// expression instead of the default chain of `OR` expressions
+fn derive_filter_by_primary_keys(
+    model: &Model,
+    backend: &TokenStream,
+) -> Result<Option<TokenStream>, Diagnostic> {
+    // Composite keys use the `OR` chain of the default implementation,
+    // see `LoadingHandler::filter_by_primary_keys`
+    let primary_key = match model.primary_key() {
+        [primary_key] => primary_key,
+        _ => return Ok(None),
+    };
+    let table = model.table_type()?;
+    Ok(Some(quote! {
+        fn filter_by_primary_keys<'a>(
+            query: wundergraph::query_builder::selection::BoxedQuery<'a, Self, #backend, __Ctx>,
+            keys: std::vec::Vec<
+                <<&'static Self as wundergraph::diesel::Identifiable>::Id as wundergraph::helper::UnRef<'static>>::UnRefed
+            >,
+        ) -> wundergraph::query_builder::selection::BoxedQuery<'a, Self, #backend, __Ctx> {
+            use wundergraph::diesel::{ExpressionMethods, QueryDsl};
+            query.filter(#table::#primary_key.eq_any(keys))
+        }
+    }))
+}
+
+fn derive_reference_order(
+    model: &Model,
+    backend: &TokenStream,
+) -> Result<Option<TokenStream>, Diagnostic> {
+    let table = model.table_type()?;
+    let references = model
+        .fields()
+        .iter()
+        .filter_map(|f| {
+            has_one_target(&f.ty).map(|target| {
+                let column = f.sql_name();
+                let name = f.graphql_name();
+                (
+                    quote!(wundergraph::query_builder::selection::reference_order::ReferenceOrder::<
+                        #table::#column,
+                        #target,
+                        #backend,
+                        __Ctx,
+                    >),
+                    name,
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    let counts = model
+        .fields()
+        .iter()
+        .filter_map(|f| {
+            has_many_foreign_key(&f.ty).map(|fk| {
+                let name = f.graphql_name();
+                (
+                    quote!(wundergraph::query_builder::selection::reference_order::CountOrder::<
+                        <#table::table as wundergraph::diesel::Table>::PrimaryKey,
+                        #fk,
+                        #backend,
+                    >),
+                    name,
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    if references.is_empty() && counts.is_empty() {
+        return Ok(None);
+    }
+    let columns = references
+        .iter()
+        .map(|(reference, name)| {
+            quote! {
+                columns.extend(#reference::order_columns(stringify!(#name)));
+            }
+        })
+        .chain(counts.iter().map(|(count, name)| {
+            quote! {
+                columns.push(#count::order_column(stringify!(#name)));
+            }
+        }));
+    let orders = references
+        .iter()
+        .map(|(reference, name)| {
+            quote! {
+                let prefix = concat!(stringify!(#name), "_");
+                if column.starts_with(prefix) {
+                    if let std::option::Option::Some(o) = #reference::build_order(&column[prefix.len()..], order, nulls) {
+                        return std::option::Option::Some(o);
+                    }
+                }
+            }
+        })
+        .chain(counts.iter().map(|(count, name)| {
+            quote! {
+                if column == #count::order_column(stringify!(#name)) {
+                    return std::option::Option::Some(#count::build_order(order, nulls));
+                }
+            }
+        }));
+
+    Ok(Some(quote! {
+        fn reference_order_columns() -> std::vec::Vec<std::string::String> {
+            let mut columns = std::vec::Vec::new();
+            #(#columns)*
+            columns
+        }
+
+        fn build_reference_order(
+            column: &str,
+            order: wundergraph::query_builder::selection::order::Order,
+            nulls: std::option::Option<wundergraph::query_builder::selection::order::Nulls>,
+        ) -> std::option::Option<wundergraph::query_builder::selection::order::OrderClauses<
+            #table::table,
+            #backend,
+        >> {
+            #(#orders)*
+            std::option::Option::None
+        }
+    }))
+}
+
+/// Whether a field could be aggregated
+///
+/// Numeric fields support all aggregate functions, temporal fields
+/// only `min` and `max`
+#[derive(Clone, Copy, PartialEq)]
+enum AggregateKind {
+    Numeric,
+    Temporal,
+}
+
+fn aggregate_kind(ty: &syn::Type) -> Option<AggregateKind> {
+    if let syn::Type::Path(ref ty) = *inner_of_option_ty(ty) {
+        let last_segment = ty.path.segments.iter().last()?;
+        match &*last_segment.ident.to_string() {
+            "i16" | "i32" | "i64" | "f32" | "f64" => Some(AggregateKind::Numeric),
+            "NaiveDate" | "NaiveDateTime" | "DateTime" => Some(AggregateKind::Temporal),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+fn derive_aggregates(
+    model: &Model,
+    backend: &TokenStream,
+) -> Result<Option<TokenStream>, Diagnostic> {
+    let table = model.table_type()?;
+    let aggregates = model
+        .fields()
+        .iter()
+        // Aggregates of primary keys are meaningless
+        .filter(|f| !model.primary_key().contains(f.sql_name()))
+        .filter_map(|f| {
+            aggregate_kind(&f.ty).map(|kind| {
+                let column = f.sql_name();
+                let ty = inner_of_option_ty(&f.ty);
+                let name = f.graphql_name();
+                let condition = if kind == AggregateKind::Temporal {
+                    Some(quote! {
+                        (function == wundergraph::query_builder::selection::aggregate::AggregateFunction::Min
+                            || function == wundergraph::query_builder::selection::aggregate::AggregateFunction::Max)
+                    })
+                } else {
+                    None
+                };
+                (
+                    quote!(wundergraph::query_builder::selection::aggregate::AggregateColumn::<
+                        #table::#column,
+                        #ty,
+                    >),
+                    name,
+                    condition,
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    if aggregates.is_empty() {
+        return Ok(None);
+    }
+    let register = aggregates.iter().map(|(aggregate, name, condition)| {
+        let register = quote! {
+            fields.push(#aggregate::register_field(function, stringify!(#name), registry));
+        };
+        if let Some(condition) = condition {
+            quote!(if #condition { #register })
+        } else {
+            register
+        }
+    });
+    let names = aggregates
+        .iter()
+        .map(|(_, name, _)| name)
+        .collect::<Vec<_>>();
+    let values = (0..aggregates.len())
+        .map(|i| syn::Ident::new(&format!("value_{}", i), Span::call_site()))
+        .collect::<Vec<_>>();
+    // The aggregates of all columns are selected as nested pairs
+    // `(a, (b, (c, d)))` matching the nested tuples they are loaded into
+    let (select, pattern) = aggregates
+        .iter()
+        .zip(&values)
+        .rev()
+        .map(|((aggregate, name, _), value)| {
+            (
+                quote!(#aggregate::select(stringify!(#name), requested)),
+                quote!(#value),
+            )
+        })
+        .fold(None, |acc, (select, pattern)| {
+            Some(match acc {
+                None => (select, pattern),
+                Some((acc_select, acc_pattern)) => (
+                    quote! {
+                        wundergraph::query_builder::selection::aggregate::AggregateSelect::new(
+                            #select,
+                            #acc_select,
+                        )
+                    },
+                    quote!((#pattern, #acc_pattern)),
+                ),
+            })
+        })
+        .expect("There is at least one aggregate");
+    let resolve = aggregates
+        .iter()
+        .zip(&values)
+        .map(|((aggregate, name, _), value)| {
+            quote! {
+                #aggregate::resolve(stringify!(#name), #value, requested, &mut values, executor)?;
+            }
+        });
+
+    Ok(Some(quote! {
+        fn aggregate_fields<'r>(
+            function: wundergraph::query_builder::selection::aggregate::AggregateFunction,
+            registry: &mut wundergraph::juniper::Registry<'r, wundergraph::scalar::WundergraphScalarValue>,
+        ) -> std::vec::Vec<wundergraph::juniper::meta::Field<'r, wundergraph::scalar::WundergraphScalarValue>> {
+            let mut fields = std::vec::Vec::new();
+            #(#register)*
+            fields
+        }
+
+        fn load_aggregate_values<'a>(
+            requested: &[(
+                wundergraph::query_builder::selection::aggregate::AggregateFunction,
+                &str,
+            )],
+            query: wundergraph::query_builder::selection::aggregate::AggregateQuery<'a, Self, #backend, __Ctx>,
+            executor: &wundergraph::juniper::Executor<'_, __Ctx, wundergraph::scalar::WundergraphScalarValue>,
+        ) -> wundergraph::error::Result<std::vec::Vec<
+            wundergraph::query_builder::selection::aggregate::AggregateRow<
+                wundergraph::query_builder::selection::aggregate::GroupKey<Self, #backend, __Ctx>,
+            >,
+        >>
+        where
+            __Ctx: wundergraph::WundergraphContext,
+            <__Ctx as wundergraph::WundergraphContext>::Connection: wundergraph::diesel::Connection<Backend = #backend>,
+            #backend: wundergraph::diesel::sql_types::HasSqlType<(
+                wundergraph::query_builder::selection::aggregate::GroupKeySqlType<Self, #backend, __Ctx>,
+                wundergraph::diesel::sql_types::BigInt,
+            )>,
+            <#table::table as wundergraph::diesel::QuerySource>::FromClause: wundergraph::diesel::query_builder::QueryFragment<#backend>,
+            (
+                wundergraph::query_builder::selection::aggregate::GroupKey<Self, #backend, __Ctx>,
+                i64,
+            ): wundergraph::diesel::Queryable<(
+                wundergraph::query_builder::selection::aggregate::GroupKeySqlType<Self, #backend, __Ctx>,
+                wundergraph::diesel::sql_types::BigInt,
+            ), #backend>,
+        {
+            if let std::option::Option::Some((_, field)) = requested
+                .iter()
+                .find(|(_, field)| ![#(stringify!(#names)),*].contains(field))
+            {
+                return std::result::Result::Err(wundergraph::error::WundergraphError::UnknownDatabaseField {
+                    name: (*field).to_owned(),
+                });
+            }
+            let rows = query.load(
+                wundergraph::query_builder::selection::aggregate::AggregateSelect::new(
+                    wundergraph::diesel::dsl::count_star(),
+                    #select,
+                ),
+                wundergraph::WundergraphContext::get_connection(executor.context()),
+            )?;
+            rows.into_iter()
+                .map(|(key, (count, #pattern))| {
+                    let mut values = std::vec![wundergraph::juniper::Value::null(); requested.len()];
+                    #(#resolve)*
+                    std::result::Result::Ok(wundergraph::query_builder::selection::aggregate::AggregateRow {
+                        key,
+                        count,
+                        values,
+                    })
+                })
+                .collect()
+        }
+    }))
+}
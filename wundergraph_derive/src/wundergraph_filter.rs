@@ -64,9 +64,20 @@ fn build_filter(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
     } else {
         None
     };
+    let mysql = if cfg!(feature = "mysql") {
+        Some(impl_build_filter(
+            item,
+            &fields,
+            &quote!(diesel::mysql::Mysql),
+            table,
+        ))
+    } else {
+        None
+    };
     Ok(quote! {
         #pg
         #sqlite
+        #mysql
     })
 }
 
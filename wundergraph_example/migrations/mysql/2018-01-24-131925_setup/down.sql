@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+
+DROP TABLE friends;
+DROP TABLE appears_in;
+DROP TABLE heros;
+DROP TABLE home_worlds;
+DROP TABLE species;
@@ -0,0 +1 @@
+CREATE UNIQUE INDEX home_worlds_name_key ON home_worlds(name);
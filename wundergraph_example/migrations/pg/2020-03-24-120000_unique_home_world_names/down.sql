@@ -0,0 +1 @@
+DROP INDEX home_worlds_name_key;
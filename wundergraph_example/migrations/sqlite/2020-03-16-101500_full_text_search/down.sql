@@ -0,0 +1,4 @@
+DROP TRIGGER heros_fts_update;
+DROP TRIGGER heros_fts_delete;
+DROP TRIGGER heros_fts_insert;
+DROP TABLE heros_fts;
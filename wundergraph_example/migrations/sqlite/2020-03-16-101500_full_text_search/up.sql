@@ -0,0 +1,21 @@
+CREATE VIRTUAL TABLE heros_fts USING fts5(
+    name,
+    hair_color,
+    content='heros',
+    content_rowid='id'
+);
+
+INSERT INTO heros_fts(heros_fts) VALUES('rebuild');
+
+CREATE TRIGGER heros_fts_insert AFTER INSERT ON heros BEGIN
+    INSERT INTO heros_fts(rowid, name, hair_color) VALUES (new.id, new.name, new.hair_color);
+END;
+
+CREATE TRIGGER heros_fts_delete AFTER DELETE ON heros BEGIN
+    INSERT INTO heros_fts(heros_fts, rowid, name, hair_color) VALUES ('delete', old.id, old.name, old.hair_color);
+END;
+
+CREATE TRIGGER heros_fts_update AFTER UPDATE ON heros BEGIN
+    INSERT INTO heros_fts(heros_fts, rowid, name, hair_color) VALUES ('delete', old.id, old.name, old.hair_color);
+    INSERT INTO heros_fts(rowid, name, hair_color) VALUES (new.id, new.name, new.hair_color);
+END;
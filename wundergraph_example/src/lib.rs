@@ -132,6 +132,7 @@ table! {
 #[derive(Clone, Debug, Identifiable, Queryable, WundergraphEntity)]
 #[primary_key(hero_id, episode)]
 #[table_name = "appears_in"]
+#[wundergraph(default_order = "episode desc")]
 pub struct AppearsIn {
     hero_id: HasOne<i32, Hero>,
     episode: Episode,
@@ -148,11 +149,13 @@ pub struct Friend {
 
 #[derive(Clone, Debug, Identifiable, WundergraphEntity)]
 #[table_name = "home_worlds"]
+#[wundergraph(default_order = "name asc")]
 /// A world where a hero was born
 pub struct HomeWorld {
     /// Internal id of a world
     id: i32,
     /// The name of a world
+    #[wundergraph(unique)]
     name: String,
     /// All heros of a given world
     heros: HasMany<Hero, heros::home_world>,
@@ -163,6 +166,7 @@ mod hero {
     use super::*;
     #[derive(Clone, Debug, Identifiable, Queryable, WundergraphEntity)]
     #[table_name = "heros"]
+    #[wundergraph(full_text_search = "heros_fts")]
     /// A hero from Star Wars
     pub struct Hero {
         /// Internal id of a hero
@@ -202,10 +206,12 @@ wundergraph::query_object! {
     /// Global query object for the schema
     Query {
         /// Access to Heros
+        #[wundergraph(connection = true, count = true, aggregate = true, by_ids = true)]
         Hero,
         /// Access to Species
         Species,
         /// Access to HomeWorlds
+        #[wundergraph(connection = keyset, by_ids = true)]
         HomeWorld,
     }
 }
@@ -216,6 +222,8 @@ where
     Conn: Connection + 'static,
 {
     conn: PooledConnection<ConnectionManager<Conn>>,
+    default_limit: Option<i64>,
+    max_limit: Option<i64>,
 }
 
 impl<Conn> MyContext<Conn>
@@ -223,7 +231,23 @@ where
     Conn: Connection + 'static,
 {
     pub fn new(conn: PooledConnection<ConnectionManager<Conn>>) -> Self {
-        Self { conn }
+        Self {
+            conn,
+            default_limit: None,
+            max_limit: None,
+        }
+    }
+
+    /// Limit applied to top level queries without a `limit` argument
+    pub fn with_default_limit(mut self, limit: i64) -> Self {
+        self.default_limit = Some(limit);
+        self
+    }
+
+    /// Largest limit a top level query may request
+    pub fn with_max_limit(mut self, limit: i64) -> Self {
+        self.max_limit = Some(limit);
+        self
     }
 }
 
@@ -253,6 +277,14 @@ impl WundergraphContext for MyContext<DBConnection> {
     fn get_connection(&self) -> &Self::Connection {
         &self.conn
     }
+
+    fn default_limit(&self) -> Option<i64> {
+        self.default_limit
+    }
+
+    fn max_limit(&self) -> Option<i64> {
+        self.max_limit
+    }
 }
 
 #[cfg(feature = "postgres")]
@@ -261,6 +293,9 @@ pub type DBConnection = ::diesel::PgConnection;
 #[cfg(feature = "sqlite")]
 pub type DBConnection = ::diesel::SqliteConnection;
 
+#[cfg(feature = "mysql")]
+pub type DBConnection = ::diesel::MysqlConnection;
+
 pub type DbBackend = <DBConnection as Connection>::Backend;
 
 pub type Schema<Ctx> =
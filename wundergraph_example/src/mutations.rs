@@ -10,6 +10,8 @@ use super::Hero;
 use super::HomeWorld;
 use super::Species;
 use juniper::*;
+#[cfg(feature = "mysql")]
+use wundergraph::query_builder::mutations::InsertedKey;
 
 #[derive(Insertable, GraphQLInputObject, Clone, Debug)]
 #[table_name = "heros"]
@@ -20,6 +22,9 @@ pub struct NewHero {
     home_world: Option<i32>,
 }
 
+#[cfg(feature = "mysql")]
+impl InsertedKey<i32> for NewHero {}
+
 #[derive(AsChangeset, GraphQLInputObject, Identifiable, Debug)]
 #[table_name = "heros"]
 pub struct HeroChangeset {
@@ -36,6 +41,9 @@ pub struct NewSpecies {
     name: String,
 }
 
+#[cfg(feature = "mysql")]
+impl InsertedKey<i32> for NewSpecies {}
+
 #[derive(AsChangeset, GraphQLInputObject, Identifiable, Debug)]
 #[table_name = "species"]
 pub struct SpeciesChangeset {
@@ -49,6 +57,9 @@ pub struct NewHomeWorld {
     name: String,
 }
 
+#[cfg(feature = "mysql")]
+impl InsertedKey<i32> for NewHomeWorld {}
+
 #[derive(AsChangeset, GraphQLInputObject, Identifiable, Debug)]
 #[table_name = "home_worlds"]
 pub struct HomeWorldChangeset {
@@ -63,6 +74,13 @@ pub struct NewFriend {
     friend_id: i32,
 }
 
+#[cfg(feature = "mysql")]
+impl InsertedKey<(i32, i32)> for NewFriend {
+    fn inserted_key(&self) -> Option<(i32, i32)> {
+        Some((self.hero_id, self.friend_id))
+    }
+}
+
 #[derive(Insertable, GraphQLInputObject, Debug, Copy, Clone)]
 #[table_name = "appears_in"]
 pub struct NewAppearsIn {
@@ -70,6 +88,13 @@ pub struct NewAppearsIn {
     episode: Episode,
 }
 
+#[cfg(feature = "mysql")]
+impl InsertedKey<(i32, Episode)> for NewAppearsIn {
+    fn inserted_key(&self) -> Option<(i32, Episode)> {
+        Some((self.hero_id, self.episode))
+    }
+}
+
 wundergraph::mutation_object! {
     /// Global mutation object for the schema
     Mutation {